@@ -1,14 +1,42 @@
-use crate::Pod;
+use crate::value::error::Error;
+use crate::{EngineError, Pod, StringifyOptions};
+use std::collections::HashMap;
 
+#[doc(hidden)]
+pub mod custom;
 #[doc(hidden)]
 pub mod json;
+#[cfg(feature = "json5")]
+#[doc(hidden)]
+pub mod json5;
+#[cfg(feature = "msgpack")]
+#[doc(hidden)]
+pub mod msgpack;
+#[doc(hidden)]
+pub mod no_engine;
+#[cfg(feature = "ron")]
+#[doc(hidden)]
+pub mod ron;
 #[doc(hidden)]
 pub mod toml;
 #[doc(hidden)]
 pub mod yaml;
 
+#[doc(inline)]
+pub use crate::engine::custom::Custom;
 #[doc(inline)]
 pub use crate::engine::json::JSON;
+#[cfg(feature = "json5")]
+#[doc(inline)]
+pub use crate::engine::json5::JSON5;
+#[cfg(feature = "msgpack")]
+#[doc(inline)]
+pub use crate::engine::msgpack::MsgPack;
+#[doc(inline)]
+pub use crate::engine::no_engine::NoEngine;
+#[cfg(feature = "ron")]
+#[doc(inline)]
+pub use crate::engine::ron::RON;
 #[doc(inline)]
 pub use crate::engine::toml::TOML;
 #[doc(inline)]
@@ -17,7 +45,113 @@ pub use crate::engine::yaml::YAML;
 /// The trait requirement used by [`Matter`](crate::Matter) when parsing the front matter.
 ///
 /// Implementing this trait in your own engine will allow you to create a custom front matter
-/// format that can be used by [gray_matter](crate).
+/// format that can be used by [gray_matter](crate). Only [`parse`](Engine::parse) is required:
+/// it gets the front matter without its delimiters, and returns its data as a
+/// [`Pod`](crate::Pod). Implement [`Stringify`](crate::engine::Stringify) as well to support
+/// [`Matter::stringify`](crate::Matter::stringify).
+///
+/// An engine is a type parameter of `Matter`, never an object. To pick a format at runtime by
+/// the language tag of the front matter, like `---ini`, register a parse function with
+/// [`Matter::with_language`](crate::Matter::with_language) instead.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use gray_matter::{EngineError, Matter, Pod};
+/// # use gray_matter::engine::Engine;
+/// struct Ini;
+///
+/// impl Engine for Ini {
+///     fn parse(content: &str) -> Result<Pod, EngineError> {
+///         let mut data = Pod::new_hash();
+///         for line in content.lines() {
+///             let (key, value) = line
+///                 .split_once('=')
+///                 .ok_or_else(|| EngineError::new("expected key=value"))?;
+///             data[key.trim()] = Pod::String(value.trim().to_owned());
+///         }
+///         Ok(data)
+///     }
+/// }
+///
+/// let matter: Matter<Ini> = Matter::new();
+/// let result = matter.parse("---\ntitle = Home\n---\nOther stuff");
+///
+/// assert_eq!(result.data.unwrap()["title"], Pod::String("Home".to_owned()));
+/// ```
 pub trait Engine {
-    fn parse(content: &str) -> Pod;
+    /// Whether front matter is handed to [`parse`](Engine::parse) at all. Engines that leave it
+    /// unparsed, like [`NoEngine`](crate::engine::NoEngine), set this to `false`, so
+    /// [`ParsedEntity::data`](crate::ParsedEntity::data) stays `None`. Defaults to `true`.
+    const PARSES_MATTER: bool = true;
+
+    /// Whether comment lines are stripped from front matter before it is handed to
+    /// [`parse`](Engine::parse), as configured by
+    /// [`Matter::strip_comments`](crate::Matter::strip_comments). Engines for formats with their
+    /// own comment syntax, like JSON5, set this to `false`, so the
+    /// comment prefix is left for the parser. Defaults to `true`.
+    const STRIP_COMMENTS: bool = true;
+
+    /// Parses `content` into a [`Pod`](crate::Pod). Returns an
+    /// [`EngineError`](crate::EngineError) if `content` is malformed.
+    fn parse(content: &str) -> Result<Pod, EngineError>;
+
+    /// Whether `line` is a directive that may precede the opening delimiter, like `%YAML 1.2` in
+    /// YAML. Any such lines at the start of the input are skipped when looking for front matter.
+    /// Engines without directives can rely on the default, which never matches.
+    fn is_directive(_line: &str) -> bool {
+        false
+    }
+}
+
+/// An [`Engine`](crate::engine::Engine) that can also hand out the value type of its underlying
+/// parser, for features that [`Pod`](crate::Pod) flattens, like TOML datetimes. Used by
+/// [`Matter::parse_native`](crate::Matter::parse_native).
+pub trait NativeEngine: Engine {
+    /// The value type of the underlying parser.
+    type Value;
+
+    /// Parses `content` into the native value type. Returns `None` if `content` is malformed.
+    fn parse_native(content: &str) -> Option<Self::Value>;
+}
+
+/// The parse function of an engine, [`Engine::parse`](crate::engine::Engine::parse).
+pub type ParseFn = fn(&str) -> Result<Pod, EngineError>;
+
+/// Looks up the parse function of a built-in engine by language name, like `yaml` or `toml`.
+/// Matching is case-insensitive, and `yml` is accepted as an alias of `yaml`.
+pub fn by_language(language: &str) -> Option<ParseFn> {
+    match language.to_lowercase().as_str() {
+        "yaml" | "yml" => Some(YAML::parse),
+        "toml" => Some(TOML::parse),
+        "json" => Some(JSON::parse),
+        #[cfg(feature = "json5")]
+        "json5" => Some(JSON5::parse),
+        #[cfg(feature = "msgpack")]
+        "msgpack" => Some(MsgPack::parse),
+        #[cfg(feature = "ron")]
+        "ron" => Some(RON::parse),
+        _ => None,
+    }
+}
+
+/// An [`Engine`](crate::engine::Engine) that can also write a [`Pod`](crate::Pod) back into its
+/// format. Required by [`Matter::stringify`](crate::Matter::stringify).
+pub trait Stringify: Engine {
+    /// Serializes `data` into the engine's format, without any delimiters.
+    fn stringify(data: &Pod, options: &StringifyOptions) -> Result<String, Error>;
+}
+
+/// Entries of a `Pod::Hash`, in sorted order if requested by `options`.
+pub(crate) fn hash_entries<'a>(
+    hash: &'a HashMap<String, Pod>,
+    options: &StringifyOptions,
+) -> Vec<(&'a String, &'a Pod)> {
+    let mut entries: Vec<_> = hash.iter().collect();
+    if options.sort_keys {
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+    }
+    entries
 }
@@ -0,0 +1,16 @@
+use crate::engine::Engine;
+use crate::{EngineError, Pod};
+
+/// [`Engine`](crate::engine::Engine) for a [`Matter`](crate::Matter) created by
+/// [`Matter::with_parse_fn`](crate::Matter::with_parse_fn), which parses front matter with a
+/// closure instead.
+///
+/// On its own, this engine does not understand any format, and parses any front matter into
+/// `Pod::Null`.
+pub struct Custom;
+
+impl Engine for Custom {
+    fn parse(_content: &str) -> Result<Pod, EngineError> {
+        Ok(Pod::Null)
+    }
+}
@@ -1,15 +1,55 @@
-use crate::engine::Engine;
-use crate::Pod;
+use crate::engine::{hash_entries, Engine, NativeEngine, Stringify};
+use crate::value::error::Error;
+use crate::{EngineError, Pod, StringifyOptions};
 use json::JsonValue;
 
 /// [`Engine`](crate::engine::Engine) for the [JSON](https://www.json.org/) configuration format.
 pub struct JSON;
 
 impl Engine for JSON {
-    fn parse(content: &str) -> Pod {
+    fn parse(content: &str) -> Result<Pod, EngineError> {
         match json::parse(content) {
-            Ok(data) => data.into(),
-            Err(_) => Pod::Null,
+            Ok(data) => Ok(data.into()),
+            Err(err) => Err(EngineError::new(err)),
+        }
+    }
+}
+
+impl NativeEngine for JSON {
+    type Value = JsonValue;
+
+    fn parse_native(content: &str) -> Option<JsonValue> {
+        json::parse(content).ok()
+    }
+}
+
+/// Honors the `indent` and `sort_keys` [`StringifyOptions`](crate::StringifyOptions). An
+/// `indent` of `0` writes the object on a single line.
+impl Stringify for JSON {
+    fn stringify(data: &Pod, options: &StringifyOptions) -> Result<String, Error> {
+        let value = to_json(data, options);
+        Ok(if options.indent == 0 {
+            value.dump()
+        } else {
+            value.pretty(options.indent as u16)
+        })
+    }
+}
+
+fn to_json(pod: &Pod, options: &StringifyOptions) -> JsonValue {
+    match *pod {
+        Pod::Null => JsonValue::Null,
+        Pod::String(ref val) => val.as_str().into(),
+        Pod::Integer(val) => val.into(),
+        Pod::Float(val) => val.into(),
+        Pod::Boolean(val) => val.into(),
+        Pod::Array(ref vec) => JsonValue::Array(vec.iter().map(|v| to_json(v, options)).collect()),
+        Pod::Hash(ref hash) => {
+            let mut object = json::object::Object::new();
+            for (key, val) in hash_entries(hash, options) {
+                object.insert(key, to_json(val, options));
+            }
+            JsonValue::Object(object)
         }
     }
 }
@@ -50,8 +90,10 @@ impl Into<Pod> for JsonValue {
 #[cfg(test)]
 mod test {
     use crate::engine::json::JSON;
+    use crate::engine::Engine;
     use crate::entity::ParsedEntityStruct;
     use crate::matter::Matter;
+    use crate::Pod;
     use serde::Deserialize;
 
     #[test]
@@ -78,4 +120,38 @@ Other stuff"#;
         let result: ParsedEntityStruct<FrontMatter> = matter.parse_with_struct(input).unwrap();
         assert_eq!(result.data, data_expected);
     }
+
+    #[test]
+    fn test_whitespace() {
+        let matter: Matter<JSON> = Matter::new();
+        let result = matter.parse("---\n  { \"title\": \"Home\" }  \n\n---\nOther stuff");
+        assert_eq!(
+            result.data.unwrap()["title"],
+            Pod::String("Home".to_string())
+        );
+        assert_eq!(result.content, "Other stuff");
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(JSON::parse(r#"{"title": "Home""#).is_err());
+        assert!(
+            JSON::parse(r#"{"title": "Home",}"#).is_err(),
+            "should fail on a trailing comma"
+        );
+        assert!(JSON::parse(r#"{"tags": ["a", "b",]}"#).is_err());
+
+        let matter: Matter<JSON> = Matter::new();
+        let input = "---\n{ \"title\": \"Home\", }\n---\nOther stuff";
+        assert!(matter.try_parse(input).is_err());
+        assert_eq!(matter.parse(input).data, None);
+    }
+
+    #[test]
+    fn test_null() {
+        let data = JSON::parse(r#"{"subtitle": null, "list": [null], "quoted": "null"}"#).unwrap();
+        assert_eq!(data["subtitle"], Pod::Null);
+        assert_eq!(data["list"], Pod::Array(vec![Pod::Null]));
+        assert_eq!(data["quoted"], Pod::String("null".to_string()));
+    }
 }
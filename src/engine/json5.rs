@@ -0,0 +1,113 @@
+use crate::engine::json::JSON;
+use crate::engine::{Engine, Stringify};
+use crate::value::error::Error;
+use crate::{EngineError, Pod, StringifyOptions};
+
+/// [`Engine`](crate::engine::Engine) for the [JSON5](https://json5.org/) format, for
+/// human-edited front matter with comments, unquoted keys, single-quoted strings and trailing
+/// commas. Requires the `json5` feature.
+///
+/// JSON5 has its own comments, so lines starting with `#` are never stripped from the front
+/// matter, regardless of [`Matter::strip_comments`](crate::Matter::strip_comments).
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use gray_matter::{Matter, Pod};
+/// # use gray_matter::engine::JSON5;
+/// let matter: Matter<JSON5> = Matter::new();
+/// let result = matter.parse("---\n{ title: 'Home', /* note */ tags: ['a',], }\n---");
+/// let data = result.data.unwrap();
+///
+/// assert_eq!(data["title"], Pod::String("Home".to_owned()));
+/// assert_eq!(data["tags"][0], Pod::String("a".to_owned()));
+/// ```
+pub struct JSON5;
+
+impl Engine for JSON5 {
+    const STRIP_COMMENTS: bool = false;
+
+    fn parse(content: &str) -> Result<Pod, EngineError> {
+        match json5::from_str::<serde_json::Value>(content) {
+            Ok(value) => Ok(value.into()),
+            Err(err) => Err(EngineError::new(err)),
+        }
+    }
+}
+
+/// Writes plain JSON, which is valid JSON5, honoring the same
+/// [`StringifyOptions`](crate::StringifyOptions) as [`JSON`](crate::engine::JSON).
+impl Stringify for JSON5 {
+    fn stringify(data: &Pod, options: &StringifyOptions) -> Result<String, Error> {
+        JSON::stringify(data, options)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::engine::json5::JSON5;
+    use crate::engine::{Engine, Stringify};
+    use crate::matter::Matter;
+    use crate::{Pod, StringifyOptions};
+
+    #[test]
+    fn test_matter() {
+        let matter: Matter<JSON5> = Matter::new();
+        let input = r#"---
+{
+  // The page title.
+  title: 'Home',
+  /* note */ tags: ['a', 'b',],
+  weight: +3,
+  ratio: .5,
+  color: '#fff',
+  hex: 0x10,
+}
+---
+Other stuff"#;
+        let result = matter.parse(input);
+        let data = result.data.unwrap();
+        assert_eq!(data["title"], Pod::String("Home".to_string()));
+        assert_eq!(
+            data["tags"],
+            Pod::Array(vec![
+                Pod::String("a".to_string()),
+                Pod::String("b".to_string())
+            ])
+        );
+        assert_eq!(data["weight"], Pod::Integer(3));
+        assert_eq!(data["ratio"], Pod::Float(0.5));
+        assert_eq!(data["color"], Pod::String("#fff".to_string()));
+        assert_eq!(data["hex"], Pod::Integer(16));
+        assert_eq!(result.content, "Other stuff");
+    }
+
+    #[test]
+    fn test_hash_lines() {
+        let matter: Matter<JSON5> = Matter::new();
+        let result = matter.parse("---\n{\n  text: 'first \\\n# second',\n}\n---");
+        assert_eq!(result.stripped_comment_count, 0);
+        assert_eq!(
+            result.data.unwrap()["text"],
+            Pod::String("first # second".to_string()),
+            "should not strip lines starting with `#`"
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(JSON5::parse("{ title: 'Home'").is_err());
+        assert!(JSON5::parse("{ title: 'Home' } # comment").is_err());
+    }
+
+    #[test]
+    fn test_stringify() {
+        let mut data = Pod::new_hash();
+        data["title"] = Pod::String("Home".to_string());
+        data["tags"] = Pod::Array(vec![Pod::String("a".to_string())]);
+        let output = JSON5::stringify(&data, &StringifyOptions::default()).unwrap();
+        assert_eq!(JSON5::parse(&output).unwrap(), data);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::engine::{Engine, Stringify};
+use crate::value::error::Error;
+use crate::{EngineError, Pod, StringifyOptions};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+
+/// [`Engine`](crate::engine::Engine) for [MessagePack](https://msgpack.org/) front matter,
+/// written as base64 text between the delimiters. Requires the `msgpack` feature.
+///
+/// Whitespace in the base64 text, like line breaks wrapping a long payload, is ignored. Invalid
+/// base64 and malformed MessagePack both fail with an
+/// [`EngineError`](crate::EngineError). As the data goes through
+/// [`serde_json::Value`](serde_json::Value), map keys must be strings, and binary values are not
+/// supported.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use gray_matter::{Matter, Pod};
+/// # use gray_matter::engine::MsgPack;
+/// let matter: Matter<MsgPack> = Matter::new();
+/// // `{"title": "Home"}`
+/// let result = matter.parse("---\ngaV0aXRsZaRIb21l\n---\nOther stuff");
+///
+/// assert_eq!(result.data.unwrap()["title"], Pod::String("Home".to_owned()));
+/// ```
+pub struct MsgPack;
+
+impl Engine for MsgPack {
+    fn parse(content: &str) -> Result<Pod, EngineError> {
+        let encoded: String = content.split_whitespace().collect();
+        let bytes = STANDARD.decode(encoded).map_err(EngineError::new)?;
+        match rmp_serde::from_slice::<serde_json::Value>(&bytes) {
+            Ok(value) => Ok(value.into()),
+            Err(err) => Err(EngineError::new(err)),
+        }
+    }
+}
+
+/// None of the [`StringifyOptions`](crate::StringifyOptions) apply, as the front matter is
+/// written as a single line of base64 text. Map keys are always sorted.
+impl Stringify for MsgPack {
+    fn stringify(data: &Pod, _options: &StringifyOptions) -> Result<String, Error> {
+        rmp_serde::to_vec(&data.to_json_value())
+            .map(|bytes| STANDARD.encode(bytes))
+            .map_err(|e| Error::serialize_error(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::engine::msgpack::MsgPack;
+    use crate::engine::{Engine, Stringify};
+    use crate::matter::Matter;
+    use crate::{Pod, StringifyOptions};
+
+    #[test]
+    fn test_matter() {
+        let matter: Matter<MsgPack> = Matter::new();
+        // `{"title": "MsgPack", "tags": ["a", "b"], "weight": 3, "draft": false}`
+        let input =
+            "---\nhKV0aXRsZadNc2dQYWNrpHRhZ3OSoWGhYqZ3\nZWlnaHQDpWRyYWZ0wg==\n---\nOther stuff";
+        let result = matter.parse(input);
+        let data = result.data.unwrap();
+        assert_eq!(data["title"], Pod::String("MsgPack".to_string()));
+        assert_eq!(
+            data["tags"],
+            Pod::Array(vec![
+                Pod::String("a".to_string()),
+                Pod::String("b".to_string())
+            ])
+        );
+        assert_eq!(data["weight"], Pod::Integer(3));
+        assert_eq!(data["draft"], Pod::Boolean(false));
+        assert_eq!(result.content, "Other stuff");
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = MsgPack::parse("not base64!").unwrap_err();
+        assert!(err.downcast_ref::<base64::DecodeError>().is_some());
+        // Valid base64, but a truncated map.
+        let err = MsgPack::parse("gaV0aXRsZQ==").unwrap_err();
+        assert!(err.downcast_ref::<rmp_serde::decode::Error>().is_some());
+
+        let matter: Matter<MsgPack> = Matter::new();
+        assert_eq!(matter.parse("---\n%%%\n---\nOther stuff").data, None);
+    }
+
+    #[test]
+    fn test_stringify() {
+        let mut data = Pod::new_hash();
+        data["title"] = Pod::String("Home".to_string());
+        let output = MsgPack::stringify(&data, &StringifyOptions::default()).unwrap();
+        assert_eq!(output, "gaV0aXRsZaRIb21l");
+
+        data["tags"] = Pod::Array(vec![Pod::String("a".to_string())]);
+        data["ratio"] = Pod::Float(0.5);
+        data["image"] = Pod::Null;
+        let output = MsgPack::stringify(&data, &StringifyOptions::default()).unwrap();
+        assert_eq!(MsgPack::parse(&output).unwrap(), data);
+    }
+}
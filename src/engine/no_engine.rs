@@ -0,0 +1,64 @@
+use crate::engine::Engine;
+use crate::{EngineError, Pod};
+
+/// [`Engine`](crate::engine::Engine) for when only the content is of interest. Front matter is
+/// still detected and stripped from the content, but never parsed, so
+/// [`ParsedEntity::data`](crate::ParsedEntity::data) is always `None`.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use gray_matter::Matter;
+/// # use gray_matter::engine::NoEngine;
+/// let matter: Matter<NoEngine> = Matter::new();
+/// let result = matter.parse("---\ntitle: Home\n---\nOther stuff");
+///
+/// assert_eq!(result.data, None);
+/// assert_eq!(result.content, "Other stuff");
+/// ```
+pub struct NoEngine;
+
+impl Engine for NoEngine {
+    const PARSES_MATTER: bool = false;
+
+    fn parse(_content: &str) -> Result<Pod, EngineError> {
+        Ok(Pod::Null)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NoEngine;
+    use crate::Matter;
+
+    #[test]
+    fn test_matter() {
+        let matter: Matter<NoEngine> = Matter::new();
+        let result = matter.parse("---\ntitle: Home\n---\nSome excerpt\n---\nOther stuff");
+        assert_eq!(result.data, None);
+        assert_eq!(result.matter, "title: Home");
+        assert_eq!(result.excerpt, Some("Some excerpt".to_string()));
+        assert_eq!(result.content, "Some excerpt\n---\nOther stuff");
+    }
+
+    #[test]
+    fn test_language_tag() {
+        let matter: Matter<NoEngine> = Matter::new();
+        let result = matter.parse("---yaml\ntitle: Home\n---\nOther stuff");
+        assert_eq!(result.data, None);
+        assert_eq!(result.matter, "title: Home");
+        assert_eq!(result.content, "Other stuff");
+    }
+
+    #[test]
+    fn test_code_fence() {
+        let mut matter: Matter<NoEngine> = Matter::new();
+        matter.code_fence = true;
+        let result = matter.parse("```yaml\ntitle: Home\n```\nOther stuff");
+        assert_eq!(result.data, None);
+        assert_eq!(result.matter, "title: Home");
+        assert_eq!(result.content, "Other stuff");
+    }
+}
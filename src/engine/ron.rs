@@ -0,0 +1,187 @@
+use crate::engine::{Engine, NativeEngine, Stringify};
+use crate::value::error::Error;
+use crate::{EngineError, Pod, StringifyOptions};
+use ron::ser::PrettyConfig;
+use ron::{Number, Value as RonValue};
+
+/// [`Engine`](crate::engine::Engine) for the [RON](https://github.com/ron-rs/ron) (Rusty Object
+/// Notation) format. Requires the `ron` feature.
+///
+/// Structs, like `(title: "Home")`, are parsed into hashes, and tuples into arrays. RON's untyped
+/// value does not keep the names of structs and enum variants, so a unit variant like `Post` is
+/// parsed into `Pod::Null`, and the fields of a tuple variant into an array. `Some(value)` is
+/// parsed into the value, and `None` into `Pod::Null`.
+pub struct RON;
+
+impl Engine for RON {
+    fn parse(content: &str) -> Result<Pod, EngineError> {
+        match ron::from_str::<RonValue>(content) {
+            Ok(value) => Ok(value.into()),
+            Err(err) => Err(EngineError::new(err)),
+        }
+    }
+}
+
+impl NativeEngine for RON {
+    type Value = RonValue;
+
+    fn parse_native(content: &str) -> Option<RonValue> {
+        ron::from_str(content).ok()
+    }
+}
+
+/// Honors the `indent` [`StringifyOptions`](crate::StringifyOptions). An `indent` of `0` writes
+/// the front matter on a single line. Map keys are always sorted, and strings always quoted.
+/// `Pod::Null` is written as the unit value, `()`.
+impl Stringify for RON {
+    fn stringify(data: &Pod, options: &StringifyOptions) -> Result<String, Error> {
+        let value = to_ron(data);
+        let matter = if options.indent == 0 {
+            ron::to_string(&value)
+        } else {
+            let config = PrettyConfig::new().indentor(" ".repeat(options.indent));
+            ron::ser::to_string_pretty(&value, config)
+        };
+        matter.map_err(|e| Error::serialize_error(e.to_string()))
+    }
+}
+
+fn to_ron(pod: &Pod) -> RonValue {
+    match *pod {
+        Pod::Null => RonValue::Unit,
+        Pod::String(ref val) => RonValue::String(val.clone()),
+        Pod::Integer(val) => RonValue::Number(Number::from(val)),
+        Pod::Float(val) => RonValue::Number(Number::from(val)),
+        Pod::Boolean(val) => RonValue::Bool(val),
+        Pod::Array(ref vec) => RonValue::Seq(vec.iter().map(to_ron).collect()),
+        Pod::Hash(ref hash) => RonValue::Map(
+            hash.iter()
+                .map(|(key, val)| (RonValue::String(key.clone()), to_ron(val)))
+                .collect(),
+        ),
+    }
+}
+
+/// The key of a hash for a key of a RON map, which can be any value.
+fn to_key(value: RonValue) -> String {
+    match value {
+        RonValue::String(val) => val,
+        RonValue::Char(val) => val.to_string(),
+        value => ron::to_string(&value).unwrap_or_default(),
+    }
+}
+
+impl Into<Pod> for RonValue {
+    fn into(self) -> Pod {
+        match self {
+            RonValue::Unit | RonValue::Option(None) => Pod::Null,
+            RonValue::Option(Some(val)) => (*val).into(),
+            RonValue::Bool(val) => Pod::Boolean(val),
+            RonValue::Char(val) => Pod::String(val.to_string()),
+            RonValue::String(val) => Pod::String(val),
+            RonValue::Number(Number::Integer(val)) => Pod::Integer(val),
+            RonValue::Number(Number::Float(val)) => Pod::Float(val.get()),
+            RonValue::Seq(val) => Pod::Array(val.into_iter().map(Into::into).collect()),
+            RonValue::Map(val) => {
+                let mut pod = Pod::new_hash();
+                for (key, val) in val.into_iter() {
+                    pod[to_key(key)] = val.into();
+                }
+                pod
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::engine::ron::RON;
+    use crate::engine::{Engine, Stringify};
+    use crate::entity::ParsedEntityStruct;
+    use crate::matter::Matter;
+    use crate::{Pod, StringifyOptions};
+    use serde::Deserialize;
+
+    #[test]
+    fn test_matter() {
+        let matter: Matter<RON> = Matter::new();
+        let input = r#"---
+(
+    title: "RON",
+    tags: ["a", "b"],
+    position: (1, 2.5),
+    layout: "post",
+    summary: Some("Front matter"),
+    image: None,
+)
+---
+Other stuff"#;
+        #[derive(Deserialize, PartialEq, Debug)]
+        #[serde(rename_all = "lowercase")]
+        enum Layout {
+            Post,
+            Page,
+        }
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            title: String,
+            tags: Vec<String>,
+            position: (i64, f64),
+            layout: Layout,
+            summary: Option<String>,
+            image: Option<String>,
+        }
+        let data_expected = FrontMatter {
+            title: "RON".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+            position: (1, 2.5),
+            layout: Layout::Post,
+            summary: Some("Front matter".to_string()),
+            image: None,
+        };
+        let result: ParsedEntityStruct<FrontMatter> = matter.parse_with_struct(input).unwrap();
+        assert_eq!(result.data, data_expected);
+        assert_eq!(result.content, "Other stuff");
+    }
+
+    #[test]
+    fn test_values() {
+        let data = RON::parse("{'c': 'x', 1: Post, \"tuple\": Variant(1, true)}").unwrap();
+        assert_eq!(data["c"], Pod::String("x".to_string()));
+        assert_eq!(
+            data["1"],
+            Pod::Null,
+            "should lose the name of a unit variant"
+        );
+        assert_eq!(
+            data["tuple"],
+            Pod::Array(vec![Pod::Integer(1), Pod::Boolean(true)])
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(RON::parse("(title: \"Home\"").is_err());
+    }
+
+    #[test]
+    fn test_stringify() {
+        let mut data = Pod::new_hash();
+        data["title"] = Pod::String("RON".to_string());
+        data["position"] = Pod::Array(vec![Pod::Integer(1), Pod::Float(2.5)]);
+        data["image"] = Pod::Null;
+        let options = StringifyOptions {
+            indent: 0,
+            ..StringifyOptions::default()
+        };
+        let output = RON::stringify(&data, &options).unwrap();
+        assert_eq!(
+            output,
+            "{\"image\":(),\"position\":[1,2.5],\"title\":\"RON\"}"
+        );
+        assert_eq!(RON::parse(&output).unwrap(), data);
+
+        let output = RON::stringify(&data, &StringifyOptions::default()).unwrap();
+        assert_eq!(RON::parse(&output).unwrap(), data);
+    }
+}
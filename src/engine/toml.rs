@@ -1,19 +1,60 @@
-use crate::engine::Engine;
-use crate::Pod;
+use crate::engine::{Engine, NativeEngine, Stringify};
+use crate::value::error::Error;
+use crate::{EngineError, Pod, StringifyOptions};
 use toml::Value as TomlValue;
 
 /// [`Engine`](crate::engine::Engine) for the [TOML](https://toml.io/) configuration format.
 pub struct TOML;
 
 impl Engine for TOML {
-    fn parse(content: &str) -> Pod {
+    fn parse(content: &str) -> Result<Pod, EngineError> {
         match toml::from_str::<TomlValue>(content) {
-            Ok(value) => value.into(),
-            Err(..) => Pod::Null,
+            Ok(value) => Ok(value.into()),
+            Err(err) => Err(EngineError::new(err)),
         }
     }
 }
 
+impl NativeEngine for TOML {
+    type Value = TomlValue;
+
+    fn parse_native(content: &str) -> Option<TomlValue> {
+        toml::from_str(content).ok()
+    }
+}
+
+/// TOML strings are always quoted and table keys are always sorted, so none of the
+/// [`StringifyOptions`](crate::StringifyOptions) apply. As TOML has no null value, keys holding
+/// `Pod::Null` are left out.
+impl Stringify for TOML {
+    fn stringify(data: &Pod, _options: &StringifyOptions) -> Result<String, Error> {
+        match to_toml(data) {
+            Some(value @ TomlValue::Table(_)) => toml::to_string(&value)
+                .map(|matter| matter.trim_end().to_string())
+                .map_err(|e| Error::serialize_error(e.to_string())),
+            _ => Err(Error::serialize_error(
+                "TOML front matter must be a table".to_string(),
+            )),
+        }
+    }
+}
+
+fn to_toml(pod: &Pod) -> Option<TomlValue> {
+    match *pod {
+        Pod::Null => None,
+        Pod::String(ref val) => Some(TomlValue::String(val.clone())),
+        Pod::Integer(val) => Some(TomlValue::Integer(val)),
+        Pod::Float(val) => Some(TomlValue::Float(val)),
+        Pod::Boolean(val) => Some(TomlValue::Boolean(val)),
+        Pod::Array(ref vec) => Some(TomlValue::Array(vec.iter().filter_map(to_toml).collect())),
+        Pod::Hash(ref hash) => Some(TomlValue::Table(
+            hash.iter()
+                .filter_map(|(key, val)| Some((key.clone(), to_toml(val)?)))
+                .collect(),
+        )),
+    }
+}
+
 impl Into<Pod> for TomlValue {
     fn into(self) -> Pod {
         match self {
@@ -43,6 +84,7 @@ impl Into<Pod> for TomlValue {
 #[cfg(test)]
 mod test {
     use crate::engine::toml::TOML;
+    use crate::engine::{Engine, Stringify};
     use crate::entity::ParsedEntityStruct;
     use crate::matter::Matter;
     use serde::Deserialize;
@@ -72,4 +114,54 @@ categories = "front matter toml"
         let result: ParsedEntityStruct<FrontMatter> = matter.parse_with_struct(input).unwrap();
         assert_eq!(result.data, data_expected);
     }
+
+    #[test]
+    fn test_malformed() {
+        assert!(TOML::parse("title = [\"Home\"").is_err());
+    }
+
+    #[test]
+    fn test_null() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            title: String,
+            subtitle: Option<String>,
+        }
+        // TOML has no null, so a null field is written by leaving the key out.
+        let data = TOML::parse("title = \"Home\"").unwrap();
+        assert!(!data.path_exists("subtitle"));
+        let front_matter: FrontMatter = data.deserialize().unwrap();
+        assert_eq!(front_matter.subtitle, None);
+    }
+
+    #[test]
+    fn test_parse_native() {
+        let matter: Matter<TOML> = Matter::new();
+        let input = "---\ntitle = \"TOML\"\ndate = 1979-05-27T07:32:00Z\n---\ncontent";
+        let value = matter.parse_native(input).unwrap();
+        let date = value["date"]
+            .as_datetime()
+            .expect("should get a native datetime");
+        assert_eq!(date.to_string(), "1979-05-27T07:32:00Z");
+        assert_eq!(value["title"].as_str(), Some("TOML"));
+        assert!(matter.parse_native("no front matter").is_none());
+    }
+
+    #[test]
+    fn test_stringify() {
+        use crate::{Pod, StringifyOptions};
+        let mut data = Pod::new_hash();
+        data["title"] = Pod::String("TOML".to_string());
+        data["nothing"] = Pod::Null;
+        data["author"]["name"] = Pod::String("Jane".to_string());
+        let output = TOML::stringify(&data, &StringifyOptions::default()).unwrap();
+        assert_eq!(output, "title = \"TOML\"\n\n[author]\nname = \"Jane\"");
+        data.remove("nothing".to_string());
+        assert_eq!(
+            TOML::parse(&output).unwrap(),
+            data,
+            "should leave out null values"
+        );
+        assert!(TOML::stringify(&Pod::Integer(1), &StringifyOptions::default()).is_err());
+    }
 }
@@ -1,25 +1,146 @@
-use crate::engine::Engine;
-use crate::Pod;
+use crate::engine::{hash_entries, Engine, NativeEngine, Stringify};
+use crate::value::error::Error;
+use crate::{EngineError, Pod, StringifyOptions};
 use yaml_rust::{Yaml, YamlLoader};
 
 /// [`Engine`](crate::engine::Engine) for the [YAML](https://yaml.org) configuration format.
 pub struct YAML;
 
 impl Engine for YAML {
-    fn parse(content: &str) -> Pod {
+    fn parse(content: &str) -> Result<Pod, EngineError> {
         match YamlLoader::load_from_str(content) {
             Ok(docs) => {
                 let mut doc = Pod::Null;
                 if !docs.is_empty() {
                     doc = docs[0].clone().into();
                 }
-                doc
+                Ok(doc)
             }
-            Err(..) => Pod::Null,
+            Err(err) => {
+                let marker = *err.marker();
+                Err(EngineError::new(err).at(marker.line(), marker.col() + 1))
+            }
+        }
+    }
+
+    /// Matches the `%YAML` and `%TAG` directives, along with any reserved ones.
+    fn is_directive(line: &str) -> bool {
+        line.starts_with('%')
+    }
+}
+
+impl NativeEngine for YAML {
+    type Value = Yaml;
+
+    /// Returns the first document in `content`, if any.
+    fn parse_native(content: &str) -> Option<Yaml> {
+        YamlLoader::load_from_str(content).ok()?.into_iter().next()
+    }
+}
+
+/// Honors all [`StringifyOptions`](crate::StringifyOptions). The document end marker is `...`.
+impl Stringify for YAML {
+    fn stringify(data: &Pod, options: &StringifyOptions) -> Result<String, Error> {
+        let mut lines = block(data, options);
+        if options.document_end {
+            lines.push("...".to_string());
+        }
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Writes `pod` as block YAML, one line per element, indented relative to the enclosing node.
+fn block(pod: &Pod, options: &StringifyOptions) -> Vec<String> {
+    let indent = " ".repeat(options.indent);
+    let mut lines = vec![];
+    match *pod {
+        Pod::Hash(ref hash) if !hash.is_empty() => {
+            for (key, value) in hash_entries(hash, options) {
+                let key = string(key, false);
+                if is_nested(value) {
+                    lines.push(format!("{}:", key));
+                    lines.extend(block(value, options).iter().map(|l| indent.clone() + l));
+                } else {
+                    lines.push(format!("{}: {}", key, scalar(value, options)));
+                }
+            }
+        }
+        Pod::Array(ref vec) if !vec.is_empty() => {
+            // Nested nodes start on the same line as the dash, padded to the indentation width.
+            let dash = format!("{:<width$}", "-", width = options.indent.max(2));
+            let pad = " ".repeat(dash.len());
+            for item in vec.iter() {
+                if is_nested(item) {
+                    let nested = block(item, options);
+                    lines.push(dash.clone() + &nested[0]);
+                    lines.extend(nested[1..].iter().map(|l| pad.clone() + l));
+                } else {
+                    lines.push(format!("- {}", scalar(item, options)));
+                }
+            }
+        }
+        _ => lines.push(scalar(pod, options)),
+    }
+    lines
+}
+
+fn is_nested(pod: &Pod) -> bool {
+    match *pod {
+        Pod::Hash(ref hash) => !hash.is_empty(),
+        Pod::Array(ref vec) => !vec.is_empty(),
+        _ => false,
+    }
+}
+
+/// Writes `pod` as a single line of YAML. Only empty containers are written in flow style.
+fn scalar(pod: &Pod, options: &StringifyOptions) -> String {
+    match *pod {
+        Pod::Null => "null".to_string(),
+        Pod::Boolean(val) => val.to_string(),
+        Pod::Integer(val) => val.to_string(),
+        Pod::Float(val) if val.is_nan() => ".nan".to_string(),
+        Pod::Float(val) if val.is_infinite() => {
+            if val > 0.0 { ".inf" } else { "-.inf" }.to_string()
         }
+        // Debug formatting keeps the fractional part of whole numbers, so `1.0` stays a float.
+        Pod::Float(val) => format!("{:?}", val),
+        Pod::String(ref val) => string(val, options.quote_strings),
+        Pod::Array(_) => "[]".to_string(),
+        Pod::Hash(_) => "{}".to_string(),
     }
 }
 
+/// Strings are left bare only if YAML reads them back as the very same string.
+fn string(val: &str, force_quotes: bool) -> String {
+    let is_plain = match YamlLoader::load_from_str(val) {
+        Ok(ref docs) if docs.len() == 1 => docs[0].as_str() == Some(val),
+        _ => false,
+    };
+    if is_plain && !force_quotes && !val.contains('\n') {
+        val.to_string()
+    } else {
+        quote(val)
+    }
+}
+
+fn quote(val: &str) -> String {
+    let mut quoted = String::with_capacity(val.len() + 2);
+    quoted.push('"');
+    for c in val.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
 impl Into<Pod> for Yaml {
     fn into(self) -> Pod {
         match self {
@@ -49,8 +170,10 @@ impl Into<Pod> for Yaml {
 #[cfg(test)]
 mod test {
     use crate::engine::yaml::YAML;
+    use crate::engine::{Engine, Stringify};
     use crate::entity::ParsedEntityStruct;
     use crate::matter::Matter;
+    use crate::{Pod, StringifyOptions};
     use serde::Deserialize;
 
     #[test]
@@ -75,4 +198,109 @@ three: baz
         let result: ParsedEntityStruct<FrontMatter> = matter.parse_with_struct(input).unwrap();
         assert_eq!(result.data, data_expected);
     }
+
+    #[test]
+    fn test_malformed() {
+        assert!(YAML::parse("title: [Home").is_err());
+        let err = YAML::parse("title: Home\nname: @x").unwrap_err();
+        assert_eq!(err.position(), Some((2, 7)));
+    }
+
+    #[test]
+    fn test_null() {
+        let data =
+            YAML::parse("tilde: ~\nword: null\nempty:\nlist: [~, null]\nquoted: \"~\"").unwrap();
+        assert_eq!(data["tilde"], Pod::Null);
+        assert_eq!(data["word"], Pod::Null);
+        assert_eq!(data["empty"], Pod::Null);
+        assert_eq!(data["list"], Pod::Array(vec![Pod::Null, Pod::Null]));
+        assert_eq!(
+            data["quoted"],
+            Pod::String("~".to_string()),
+            "should keep a quoted tilde as a string"
+        );
+    }
+
+    #[test]
+    fn test_directives() {
+        let matter: Matter<YAML> = Matter::new();
+        let input = "%YAML 1.2\n%TAG ! tag:example.com,2000:\n---\ntitle: Home\n---\nOther stuff";
+        let result = matter.parse(input);
+        assert_eq!(
+            result.data.unwrap()["title"],
+            Pod::String("Home".to_string()),
+            "should skip directives before the opening delimiter"
+        );
+        assert_eq!(result.content, "Other stuff");
+        let result = matter.parse("%YAML 1.2\ntitle: Home\n");
+        assert!(
+            result.data.is_none(),
+            "should get no front matter without a delimiter after the directives"
+        );
+        assert_eq!(result.content, "%YAML 1.2\ntitle: Home");
+    }
+
+    #[test]
+    fn test_stringify() {
+        let mut data = Pod::new_hash();
+        data["title"] = Pod::String("Home".to_string());
+        data["draft"] = Pod::Boolean(false);
+        data["author"]["name"] = Pod::String("Jane: Doe".to_string());
+        data["tags"] = Pod::Array(vec![Pod::String("a".to_string()), Pod::Integer(1)]);
+        data["empty"] = Pod::new_array();
+        data["ratio"] = Pod::Float(1.0);
+
+        let options = StringifyOptions::default();
+        let output = YAML::stringify(&data, &options).unwrap();
+        assert_eq!(
+            output,
+            "author:\n  name: \"Jane: Doe\"\ndraft: false\nempty: []\nratio: 1.0\ntags:\n  - a\n  - 1\ntitle: Home",
+            "should sort keys and indent by two spaces by default"
+        );
+        assert_eq!(
+            YAML::parse(&output).unwrap(),
+            data,
+            "should read back the same data"
+        );
+
+        let options = StringifyOptions {
+            indent: 4,
+            quote_strings: true,
+            document_end: true,
+            ..StringifyOptions::default()
+        };
+        let output = YAML::stringify(&data, &options).unwrap();
+        assert_eq!(
+            output,
+            "author:\n    name: \"Jane: Doe\"\ndraft: false\nempty: []\nratio: 1.0\ntags:\n    - \"a\"\n    - 1\ntitle: \"Home\"\n...",
+            "should indent by four spaces, quote strings and end the document"
+        );
+        assert_eq!(
+            YAML::parse(&output).unwrap(),
+            data,
+            "should read back the same data"
+        );
+
+        let mut list = Pod::new_hash();
+        let mut item = Pod::new_hash();
+        item["id"] = Pod::Integer(1);
+        item["name"] = Pod::String("first".to_string());
+        list["items"] = Pod::Array(vec![item]);
+        let output = YAML::stringify(&list, &StringifyOptions::default()).unwrap();
+        assert_eq!(output, "items:\n  - id: 1\n    name: first");
+        assert_eq!(YAML::parse(&output).unwrap(), list);
+    }
+
+    #[test]
+    fn test_stringify_unsorted() {
+        let mut data = Pod::new_hash();
+        data["b"] = Pod::Integer(2);
+        data["a"] = Pod::Integer(1);
+        let options = StringifyOptions {
+            sort_keys: false,
+            ..StringifyOptions::default()
+        };
+        let output = YAML::stringify(&data, &options).unwrap();
+        assert!(output == "a: 1\nb: 2" || output == "b: 2\na: 1");
+    }
 }
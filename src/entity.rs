@@ -1,4 +1,7 @@
 use crate::Pod;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result};
+use std::time::Duration;
 
 /// `ParsedEntity` stores a parsed result.
 ///
@@ -25,17 +28,144 @@ use crate::Pod;
 /// ```
 #[derive(PartialEq, Debug)]
 pub struct ParsedEntity {
-    /// [`Some(Pod)`](crate::Pod) if front matter was found. `None` otherwise.
+    /// [`Some(Pod)`](crate::Pod) if front matter was found. `None` otherwise, or if the front
+    /// matter is a single value, like `true`, instead of a hash or an array, or if the engine
+    /// fails to parse it.
     pub data: Option<Pod>,
     /// The full input, but with the front matter and delimiters stripped out. Any excerpt is also
     /// part of this field.
     pub content: String,
     /// A string containing the excerpt, if found. `None` otherwise.
     pub excerpt: Option<String>,
+    /// Every excerpt, for content with several excerpt delimiters: the first one is
+    /// [`excerpt`](ParsedEntity::excerpt), followed by each non-empty region between two
+    /// successive excerpt delimiters. Empty if no excerpt is found.
+    pub excerpts: Vec<String>,
+    /// The line that ended the excerpt, exactly as it appeared in the input (including any
+    /// trailing whitespace, but without the line ending). `None` if no excerpt is found.
+    pub excerpt_delimiter_matched: Option<String>,
+    /// The number of comment lines stripped from the front matter. Always `0` if
+    /// [`Matter::strip_comments`](crate::Matter::strip_comments) is not set.
+    pub stripped_comment_count: usize,
     /// The original input.
     pub orig: String,
     /// The raw front matter. Empty string if no front matter is found.
     pub matter: String,
+    /// Whether the input has a front matter block, even if the block is empty, or is missing its
+    /// closing delimiter while
+    /// [`Matter::treat_unterminated_as_matter`](crate::Matter::treat_unterminated_as_matter) is
+    /// set.
+    pub had_front_matter: bool,
+}
+
+impl ParsedEntity {
+    /// Whether the input has a front matter block, but the block is empty, or only holds
+    /// whitespace and stripped comments, so that there is no [`data`](ParsedEntity::data).
+    /// Always `false` if there is no front matter block at all.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter = Matter::<YAML>::new();
+    ///
+    /// assert!(matter.parse("---\n# comment\n---\nOther stuff").matter_is_empty());
+    /// assert!(!matter.parse("---\ntitle: Home\n---\nOther stuff").matter_is_empty());
+    /// ```
+    pub fn matter_is_empty(&self) -> bool {
+        self.had_front_matter && self.matter.is_empty()
+    }
+
+    /// Iterates over the lines of [`content`](ParsedEntity::content), without the line endings.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter = Matter::<YAML>::new();
+    /// let result = matter.parse("---\ntitle: Home\n---\nfirst\nsecond");
+    ///
+    /// assert_eq!(result.content_lines().collect::<Vec<_>>(), vec!["first", "second"]);
+    /// ```
+    pub fn content_lines(&self) -> impl Iterator<Item = &str> {
+        self.content.lines()
+    }
+
+    /// Returns the top-level entries of [`data`](ParsedEntity::data) with every value as a
+    /// string. Strings are kept as they are, numbers and booleans are written out, `Pod::Null`
+    /// becomes an empty string, and arrays and hashes are written as JSON. Returns `None` if
+    /// there is no front matter, or if it is not a `Pod::Hash`.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter = Matter::<YAML>::new();
+    /// let result = matter.parse("---\ntitle: Home\nweight: 3\ntags: [a, b]\n---");
+    /// let map = result.data_as_string_map().unwrap();
+    ///
+    /// assert_eq!(map["title"], "Home");
+    /// assert_eq!(map["weight"], "3");
+    /// assert_eq!(map["tags"], r#"["a","b"]"#);
+    /// ```
+    pub fn data_as_string_map(&self) -> Option<HashMap<String, String>> {
+        match self.data {
+            Some(Pod::Hash(ref hash)) => Some(
+                hash.iter()
+                    .map(|(key, value)| {
+                        let value = match *value {
+                            Pod::Null => String::new(),
+                            Pod::String(ref val) => val.clone(),
+                            Pod::Integer(val) => val.to_string(),
+                            Pod::Float(val) => val.to_string(),
+                            Pod::Boolean(val) => val.to_string(),
+                            Pod::Array(_) | Pod::Hash(_) => {
+                                let json: serde_json::Value = value.clone().into();
+                                json.to_string()
+                            }
+                        };
+                        (key.clone(), value)
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the document back out, for debugging and logging: the raw front matter between `---`
+/// delimiters, followed by the content. Only the content is written if there is no front matter.
+/// This is roughly equal to [`orig`](ParsedEntity::orig), but without any custom delimiters,
+/// comments or whitespace that were stripped while parsing.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use gray_matter::Matter;
+/// # use gray_matter::engine::YAML;
+/// let matter = Matter::<YAML>::new();
+/// let result = matter.parse("---\ntitle: Home\n---\nOther stuff");
+///
+/// assert_eq!(result.to_string(), "---\ntitle: Home\n---\nOther stuff");
+/// ```
+impl Display for ParsedEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.matter.is_empty() {
+            return write!(f, "{}", self.content);
+        }
+        write!(f, "---\n{}\n---\n{}", self.matter, self.content)
+    }
 }
 
 /// `ParsedEntityStruct` stores the parsed result with the front matter deserialized into a struct `T`.
@@ -80,3 +210,166 @@ pub struct ParsedEntityStruct<T: serde::de::DeserializeOwned> {
     /// The raw front matter. Empty string if no front matter is found.
     pub matter: String,
 }
+
+/// Like [`ParsedEntity`](crate::ParsedEntity), but borrowing its text from the input, as returned
+/// by [`Matter::parse_borrowed`](crate::Matter::parse_borrowed). The slices keep the line
+/// endings of the input.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use gray_matter::{Matter, ParsedEntityRef};
+/// # use gray_matter::engine::YAML;
+/// let text = "---\ntitle: Home\n---\nHere is excerpt\n---\nHere is content";
+///
+/// let matter = Matter::<YAML>::new();
+/// let result: ParsedEntityRef = matter.parse_borrowed(text);
+///
+/// assert_eq!(result.matter, "title: Home");
+/// assert_eq!(result.excerpt, Some("Here is excerpt"));
+/// assert_eq!(result.content, "Here is excerpt\n---\nHere is content")
+/// ```
+#[derive(PartialEq, Debug)]
+pub struct ParsedEntityRef<'a> {
+    /// [`Some(Pod)`](crate::Pod) if front matter was found. `None` otherwise, or if the front
+    /// matter is a single value, like `true`, instead of a hash or an array, or if the engine
+    /// fails to parse it.
+    pub data: Option<Pod>,
+    /// The full input, but with the front matter and delimiters stripped out. Any excerpt is also
+    /// part of this field.
+    pub content: &'a str,
+    /// The excerpt, if found. `None` otherwise.
+    pub excerpt: Option<&'a str>,
+    /// The original input.
+    pub orig: &'a str,
+    /// The front matter as it appears in the input, including any comments. Empty string if no
+    /// front matter is found.
+    pub matter: &'a str,
+}
+
+/// How long each phase of [`Matter::parse_timed`](crate::Matter::parse_timed) took.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct ParseTimings {
+    /// Time spent scanning the input for delimiters, excluding the engine.
+    pub scan: Duration,
+    /// Time spent by the [engine](crate::engine) parsing the front matter.
+    pub engine: Duration,
+    /// Time spent assembling the content.
+    pub assembly: Duration,
+}
+
+impl ParseTimings {
+    /// The sum of all phases.
+    pub fn total(&self) -> Duration {
+        self.scan + self.engine + self.assembly
+    }
+}
+
+/// The shape of a document, as reported by [`Matter::inspect`](crate::Matter::inspect).
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct ParseShape {
+    /// Whether non-empty front matter was found.
+    pub has_matter: bool,
+    /// Whether an excerpt was found.
+    pub has_excerpt: bool,
+    /// The length of [`ParsedEntity::content`](crate::ParsedEntity::content), in bytes.
+    pub content_len: usize,
+    /// The length of [`ParsedEntity::matter`](crate::ParsedEntity::matter), in bytes.
+    pub matter_len: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::engine::YAML;
+    use crate::matter::Matter;
+    use crate::ParsedEntity;
+
+    #[test]
+    fn test_content_lines() {
+        let matter: Matter<YAML> = Matter::new();
+        let result = matter.parse("---\ntitle: Home\n---\n# Header\r\n\nSome text\nMore text\n");
+        let lines: Vec<&str> = result.content_lines().collect();
+        assert_eq!(lines, vec!["# Header", "", "Some text", "More text"]);
+        assert_eq!(matter.parse("").content_lines().count(), 0);
+    }
+
+    #[test]
+    fn test_data_as_string_map() {
+        let matter: Matter<YAML> = Matter::new();
+        let input = "---\ntitle: Home\nweight: 3\nratio: 0.5\ndraft: false\nsubtitle: ~\nauthor:\n  name: Jane\n---";
+        let map = matter.parse(input).data_as_string_map().unwrap();
+        assert_eq!(map.len(), 6);
+        assert_eq!(map["title"], "Home");
+        assert_eq!(map["weight"], "3");
+        assert_eq!(map["ratio"], "0.5");
+        assert_eq!(map["draft"], "false");
+        assert_eq!(map["subtitle"], "");
+        assert_eq!(map["author"], r#"{"name":"Jane"}"#);
+
+        assert_eq!(matter.parse("No front matter").data_as_string_map(), None);
+        assert_eq!(matter.parse("---\n- a\n---").data_as_string_map(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let matter: Matter<YAML> = Matter::new();
+        let input = "---\ntitle: Home\ntags:\n  - a\n---\nexcerpt\n---\ncontent";
+        assert_eq!(matter.parse(input).to_string(), input);
+        assert_eq!(
+            matter
+                .parse("---\n# comment\ntitle: Home\n---\n\ncontent\n")
+                .to_string(),
+            "---\ntitle: Home\n---\ncontent"
+        );
+        assert_eq!(matter.parse("---\n---\ncontent").to_string(), "content");
+        assert_eq!(matter.parse("content").to_string(), "content");
+    }
+
+    #[test]
+    fn test_matter_is_empty() {
+        let matter: Matter<YAML> = Matter::new();
+        let result = matter.parse("Other stuff");
+        assert!(!result.had_front_matter);
+        assert!(!result.matter_is_empty());
+
+        for input in [
+            "---\n---\nOther stuff",
+            "---\n  \n# comment\n---\nOther stuff",
+        ]
+        .iter()
+        {
+            let result = matter.parse(input);
+            assert!(result.had_front_matter);
+            assert!(result.matter_is_empty(), "{:?} should be empty", input);
+            assert_eq!(result.data, None);
+        }
+
+        let result = matter.parse("---\ntitle: Home\n---\nOther stuff");
+        assert!(result.had_front_matter);
+        assert!(!result.matter_is_empty());
+
+        let result = matter.parse("---\ntitle: Home\nOther stuff");
+        assert!(
+            !result.had_front_matter,
+            "should not count front matter without a closing delimiter"
+        );
+
+        let result = ParsedEntity {
+            data: None,
+            content: "Other stuff".to_string(),
+            excerpt: None,
+            excerpts: vec![],
+            excerpt_delimiter_matched: None,
+            stripped_comment_count: 0,
+            orig: "---\n---\nOther stuff".to_string(),
+            matter: String::new(),
+            had_front_matter: true,
+        };
+        assert!(
+            result.matter_is_empty(),
+            "should build from a struct literal"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::error;
+use std::fmt::{Display, Formatter, Result};
+use std::io;
+
+/// Errors from parsing a document with [`Matter`](crate::Matter).
+#[derive(Debug)]
+pub enum GrayMatterError {
+    /// The input has no front matter.
+    NoMatter,
+    /// The front matter was found, but could not be deserialized into the requested type. Holds
+    /// the message of the underlying serde error.
+    Deserialize(String),
+    /// An opening delimiter was found, but no closing delimiter.
+    MalformedDelimiter,
+    /// The [engine](crate::engine) failed to parse the front matter. Holds the
+    /// [`EngineError`](crate::EngineError), whose underlying error can be downcast to its
+    /// concrete type, like `yaml_rust::ScanError` for [`YAML`](crate::engine::YAML), and which
+    /// has a [`snippet`](crate::EngineError::snippet) of the front matter if the engine reports
+    /// a position.
+    EngineParse(EngineError),
+    /// The input could not be read, as by
+    /// [`Matter::parse_from_path_with_struct`](crate::Matter::parse_from_path_with_struct).
+    Io(io::Error),
+    /// A line of the input is longer than [`Matter::max_line_bytes`](crate::Matter::max_line_bytes).
+    /// Holds the number of the line, starting at 1.
+    LineTooLong(usize),
+}
+
+impl Display for GrayMatterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        use GrayMatterError::*;
+
+        match *self {
+            NoMatter => write!(f, "No front matter found"),
+            Deserialize(ref s) => write!(f, "Could not deserialize front matter: {}", s),
+            MalformedDelimiter => write!(f, "Front matter is missing its closing delimiter"),
+            EngineParse(ref err) => match err.snippet() {
+                Some(snippet) => write!(f, "Could not parse front matter: {}\n{}", err, snippet),
+                None => write!(f, "Could not parse front matter: {}", err),
+            },
+            Io(ref err) => write!(f, "Could not read input: {}", err),
+            LineTooLong(line) => write!(f, "Line {} is longer than the maximum length", line),
+        }
+    }
+}
+
+/// Engine and IO errors are compared by their message, as the underlying errors are not
+/// comparable.
+impl PartialEq for GrayMatterError {
+    fn eq(&self, other: &Self) -> bool {
+        use GrayMatterError::*;
+
+        match (self, other) {
+            (NoMatter, NoMatter) | (MalformedDelimiter, MalformedDelimiter) => true,
+            (Deserialize(a), Deserialize(b)) => a == b,
+            (EngineParse(a), EngineParse(b)) => a.to_string() == b.to_string(),
+            (Io(a), Io(b)) => a.kind() == b.kind() && a.to_string() == b.to_string(),
+            (LineTooLong(a), LineTooLong(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for GrayMatterError {}
+
+impl error::Error for GrayMatterError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            GrayMatterError::EngineParse(ref err) => Some(err.inner.as_ref()),
+            GrayMatterError::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// An error from an [engine](crate::engine) failing to parse front matter, like a syntax error.
+/// Wraps the error of the underlying parser, or just a message, along with the position of the
+/// error in the front matter, if the engine reports it.
+#[derive(Debug)]
+pub struct EngineError {
+    inner: Box<dyn error::Error + Send + Sync>,
+    position: Option<(usize, usize)>,
+    snippet: Option<String>,
+}
+
+impl EngineError {
+    /// Creates an `EngineError` from any error, or from a message given as a `&str` or `String`.
+    pub fn new<E: Into<Box<dyn error::Error + Send + Sync>>>(error: E) -> Self {
+        EngineError {
+            inner: error.into(),
+            position: None,
+            snippet: None,
+        }
+    }
+
+    /// Sets the position of the error in the front matter, as a line and a column, both starting
+    /// at 1. [`Matter`](crate::Matter) renders a [`snippet`](EngineError::snippet) from it.
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.position = Some((line, column));
+        self
+    }
+
+    /// The line and column of the error in the front matter, if the engine reports it.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        self.position
+    }
+
+    /// The line of the front matter holding the error, with a caret under its column, like:
+    ///
+    /// ```text
+    /// 2 | name: @x
+    ///   |       ^
+    /// ```
+    ///
+    /// Lines are numbered as in the front matter handed to the engine, without the delimiters
+    /// and any leading blank lines. `None` if the engine does not report a position.
+    pub fn snippet(&self) -> Option<&str> {
+        self.snippet.as_deref()
+    }
+
+    /// Renders the [`snippet`](EngineError::snippet) from `matter`, the front matter that was
+    /// handed to the engine.
+    pub(crate) fn render_snippet(&mut self, matter: &str) {
+        if let Some((line, column)) = self.position {
+            let text = matter.lines().nth(line.saturating_sub(1)).unwrap_or("");
+            let number = line.to_string();
+            let gutter = " ".repeat(number.len());
+            // Keep tabs, so the caret lines up with the text.
+            let indent: String = text
+                .chars()
+                .chain(std::iter::repeat(' '))
+                .take(column.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            self.snippet = Some(format!("{} | {}\n{} | {}^", number, text, gutter, indent));
+        }
+    }
+
+    /// Returns a reference to the underlying error if it is of type `E`.
+    pub fn downcast_ref<E: error::Error + 'static>(&self) -> Option<&E> {
+        self.inner.downcast_ref()
+    }
+
+    /// Consumes `self`, returning the underlying error.
+    pub fn into_inner(self) -> Box<dyn error::Error + Send + Sync> {
+        self.inner
+    }
+}
+
+impl Display for EngineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl error::Error for EngineError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.inner.source()
+    }
+}
+
+/// A field of the front matter that could not be deserialized, reported by
+/// [`Matter::parse_with_struct_lenient`](crate::Matter::parse_with_struct_lenient).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// The key of the field in the front matter.
+    pub field: String,
+    /// The message of the underlying serde error.
+    pub message: String,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "Could not deserialize field `{}`: {}",
+            self.field, self.message
+        )
+    }
+}
+
+impl error::Error for FieldError {}
+
+/// Top-level keys of the front matter that are not among the allowed keys, reported by
+/// [`Matter::parse_with_allowed_keys`](crate::Matter::parse_with_allowed_keys).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKeyError {
+    /// The keys that are not allowed, sorted.
+    pub keys: Vec<String>,
+}
+
+impl Display for UnknownKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "Unknown front matter keys: {}", self.keys.join(", "))
+    }
+}
+
+impl error::Error for UnknownKeyError {}
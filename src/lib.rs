@@ -1,4 +1,15 @@
 #![allow(clippy::from_over_into)]
+#![cfg_attr(
+    test,
+    allow(
+        clippy::approx_constant,
+        clippy::bool_assert_comparison,
+        clippy::bool_comparison,
+        clippy::cmp_owned,
+        clippy::len_zero,
+        clippy::unnecessary_cast
+    )
+)]
 
 //! **gray_matter** is a tool for easily extracting front matter out of a string. It is a fast Rust
 //! implementation of the original [gray-matter](https://github.com/jonschlinkert/gray-matter) by
@@ -41,8 +52,9 @@
 //! ## What formats are supported?
 //!
 //! **gray_matter** has built in support for [YAML](crate::engine::YAML),
-//! [TOML](crate::engine::TOML) and [JSON](crate::engine::JSON), but the
-//! [`Engine`](crate::engine::Engine) trait allows for virtually any format you wish to be
+//! [TOML](crate::engine::TOML) and [JSON](crate::engine::JSON), along with RON behind the `ron`
+//! feature, JSON5 behind the `json5` feature and MessagePack, written as base64 text, behind the
+//! `msgpack` feature, but the [`Engine`](crate::engine::Engine) trait allows for virtually any format you wish to be
 //! supported.
 //!
 //! # Examples
@@ -116,17 +128,30 @@ pub mod engine;
 #[doc(hidden)]
 pub mod entity;
 #[doc(inline)]
-pub use entity::{ParsedEntity, ParsedEntityStruct};
+pub use entity::{ParseShape, ParseTimings, ParsedEntity, ParsedEntityRef, ParsedEntityStruct};
+
+#[doc(hidden)]
+pub mod error;
+#[doc(inline)]
+pub use error::{EngineError, FieldError, GrayMatterError, UnknownKeyError};
 
 #[doc(hidden)]
 pub mod matter;
 #[doc(inline)]
-pub use matter::Matter;
+pub use matter::{Matter, MatterConfig};
+
+#[doc(hidden)]
+pub mod stringify;
+#[doc(inline)]
+pub use stringify::{LineEnding, StringifyOptions};
 
 #[doc(hidden)]
 pub mod value;
 #[doc(inline)]
-pub use value::{error::Error, pod::Pod};
+pub use value::{
+    error::Error,
+    pod::{KeyRename, MergePolicy, Pod},
+};
 
 #[cfg(test)]
 mod tests;
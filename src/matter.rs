@@ -1,7 +1,22 @@
-use crate::engine::Engine;
-use crate::{ParsedEntity, ParsedEntityStruct};
+use crate::engine::{self, Custom, Engine, NativeEngine, Stringify};
+use crate::value::de::BoolStringDeserializer;
+use crate::value::error::Error;
+use crate::value::pod;
+use crate::{
+    EngineError, FieldError, GrayMatterError, KeyRename, LineEnding, ParseShape, ParseTimings,
+    ParsedEntity, ParsedEntityRef, ParsedEntityStruct, Pod, StringifyOptions, UnknownKeyError,
+};
 use regex::Regex;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
 use std::marker::PhantomData;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Instant;
 
 enum Part {
     Matter,
@@ -9,14 +24,235 @@ enum Part {
     Content,
 }
 
+/// Information about a parse that is not part of the resulting `ParsedEntity`.
+#[derive(Default)]
+struct ParseDetails {
+    timings: ParseTimings,
+    /// Byte offset in the input right after the closing delimiter of the front matter, or `0` if
+    /// there is none.
+    content_start: usize,
+    /// Whether the front matter is left unparsed, regardless of the engine.
+    skip_engine: bool,
+    /// Whether an opening delimiter was found without a closing delimiter.
+    unclosed_matter: bool,
+    /// The error of the engine, if it failed to parse the front matter.
+    engine_error: Option<EngineError>,
+    /// The number of the first line longer than `Matter::max_line_bytes`, if any.
+    long_line: Option<usize>,
+}
+
+/// The parts of a document found by `Matter::scan`, as slices of the input.
+struct Scanned<'a> {
+    /// The front matter between the delimiters, as it appears in the input.
+    raw_matter: Option<&'a str>,
+    /// Everything following the front matter, untrimmed.
+    content: &'a str,
+    /// Every excerpt, trimmed.
+    excerpts: Vec<&'a str>,
+    /// The line that ended the first excerpt.
+    excerpt_delimiter_matched: Option<&'a str>,
+}
+
 /// Coupled with an [`Engine`](crate::engine::Engine) of choice, `Matter` stores delimiter(s) and
 /// handles parsing.
 pub struct Matter<T: Engine> {
+    /// The delimiter enclosing the front matter. Defaults to `---`. The opening delimiter may be
+    /// followed by a language tag, like `---toml`, to parse that front matter with the built-in
+    /// engine for the language instead (see [`engine::by_language`](crate::engine::by_language)),
+    /// or one registered by [`with_language`](Matter::with_language). Engines that do not parse
+    /// front matter, like [`NoEngine`](crate::engine::NoEngine), still leave it unparsed.
     pub delimiter: String,
+    /// The delimiter closing the front matter, for formats with a different closing fence, like
+    /// `<!--` and `-->`. Defaults to `None`, using [`delimiter`](Matter::delimiter) for both.
+    pub close_delimiter: Option<String>,
+    /// Other delimiters that may enclose the front matter, each along with the language of the
+    /// front matter it encloses, like `("+++", "toml")`, for documents from sources with
+    /// different conventions. The language picks the parse function like a language tag on
+    /// [`delimiter`](Matter::delimiter) does. Each document is checked on its own, so the blocks
+    /// split by [`parse_many`](Matter::parse_many) may use different delimiters. Defaults to
+    /// none.
+    pub alternate_delimiters: Vec<(String, String)>,
+    /// The line ending an excerpt. Defaults to `None`, using [`delimiter`](Matter::delimiter).
+    /// An empty excerpt delimiter disables excerpts, instead of matching every blank line.
+    ///
+    /// An HTML comment matches regardless of the whitespace around its text, so
+    /// `<!-- more -->` also matches `<!--more-->`.
+    ///
+    /// Front matter is detected first: an input opening with the delimiter line is front matter,
+    /// and the excerpt is only looked for in the content following it, or in the whole input if
+    /// there is no front matter. Content whose first non-blank line starts with an excerpt
+    /// delimiter, like `---` or a `-----` thematic break, has no excerpt, as that line cannot
+    /// end an excerpt that would come before it.
     pub excerpt_delimiter: Option<String>,
+    /// More lines ending an excerpt, besides [`excerpt_delimiter`](Matter::excerpt_delimiter),
+    /// like `<!-- more -->` and `{/* more */}`. Defaults to none.
+    pub excerpt_delimiters: Vec<String>,
+    /// The length, in characters, of an excerpt taken from the start of the content if no
+    /// excerpt delimiter is found, for preview snippets without a `<!-- more -->` marker. The
+    /// excerpt ends at the last word boundary within that length. Defaults to `None`, leaving
+    /// such content without an excerpt.
+    pub excerpt_length: Option<usize>,
+    /// Whether leading and trailing whitespace is trimmed from the content. Defaults to `true`.
+    pub trim: bool,
+    /// The characters trimmed from the content if [`trim`](Matter::trim) is set, instead of
+    /// whitespace. Defaults to `None`, trimming whitespace.
+    pub content_trim_chars: Option<Vec<char>>,
+    /// Whether lines starting with the [`comment_prefix`](Matter::comment_prefix) are stripped
+    /// from the front matter before it is handed to the engine. Lines inside YAML block scalars,
+    /// like `description: |`, are kept, as are lines starting with an escaped prefix, like `\#`,
+    /// which are unescaped to `#`. Defaults to `true`. Engines with their own comment syntax, like
+    /// JSON5, opt out (see
+    /// [`Engine::STRIP_COMMENTS`](crate::engine::Engine::STRIP_COMMENTS)).
+    pub strip_comments: bool,
+    /// The prefix starting comment lines in the front matter, like `//` or `;` for custom
+    /// formats. `None`, or an empty prefix, disables comment stripping. Defaults to `#`.
+    pub comment_prefix: Option<String>,
+    /// Whether front matter may also be enclosed in a code fence with a language info string,
+    /// like ```` ```yaml ````, instead of the delimiter. Such front matter is parsed by the
+    /// built-in engine for that language (see [`engine::by_language`](crate::engine::by_language)),
+    /// regardless of the engine contained in `self`. Defaults to `false`.
+    pub code_fence: bool,
+    /// Whether a byte order mark at the start of the input is ignored. Defaults to `false`.
+    pub strip_bom: bool,
+    /// Whether whitespace, including blank lines, is allowed before the opening delimiter.
+    /// Defaults to `false`.
+    pub allow_leading_whitespace: bool,
+    /// Whether every line after the front matter is content, so that no delimiter in the body,
+    /// like a Markdown horizontal rule (`---`), ever ends an excerpt. Disables excerpts. Defaults
+    /// to `false`.
+    pub disable_body_delimiters: bool,
+    /// The naming convention keys of the front matter are converted to before it is
+    /// deserialized into a custom struct, as by [`parse_with_struct`](Matter::parse_with_struct).
+    /// See [`Pod::rename_keys`](crate::Pod::rename_keys). Defaults to `None`, leaving keys as
+    /// they are.
+    pub key_rename: Option<KeyRename>,
+    /// Whether strings like `"yes"` are accepted where a custom struct expects a `bool`, when
+    /// deserializing front matter as by [`parse_with_struct`](Matter::parse_with_struct).
+    /// `"true"` and `"yes"` stand for `true`, `"false"` and `"no"` for `false`, regardless of
+    /// case. Useful for quoted values like `draft: "true"`. Defaults to `false`.
+    pub coerce_bool_strings: bool,
+    /// Whether the [`content`](crate::ParsedEntity::content), [`matter`](crate::ParsedEntity::matter)
+    /// and [`excerpt`](crate::ParsedEntity::excerpt) keep the dominant line ending of the input,
+    /// as found by [`LineEnding::detect`](crate::LineEnding::detect), instead of always using
+    /// `\n`. Defaults to `false`.
+    pub preserve_line_endings: bool,
+    /// Whether input with an opening delimiter but no closing delimiter, like a data file that
+    /// is all front matter, is parsed as all front matter, with empty content. Otherwise, such
+    /// input is all content. Defaults to `false`.
+    pub treat_unterminated_as_matter: bool,
+    /// Whether [`try_parse`](Matter::try_parse) fails on input with an opening delimiter but no
+    /// closing delimiter, instead of treating it as content like [`parse`](Matter::parse) does.
+    /// Has no effect if [`treat_unterminated_as_matter`](Matter::treat_unterminated_as_matter)
+    /// is set. Defaults to `false`.
+    pub require_balanced_delimiters: bool,
+    /// The maximum length of a line of input, in bytes, without its line ending, to guard against
+    /// pathological input, like a single huge line. Input with a longer line is not parsed:
+    /// [`parse`](Matter::parse) returns neither data nor content for it, and
+    /// [`try_parse`](Matter::try_parse) and [`parse_reader`](Matter::parse_reader) fail with
+    /// [`GrayMatterError::LineTooLong`](crate::GrayMatterError::LineTooLong). The reader never
+    /// reads much more of such a line than the limit. Defaults to `None`, allowing lines of any
+    /// length.
+    pub max_line_bytes: Option<usize>,
+    /// Whether indentation common to every line of the front matter, like that left by a code
+    /// formatter, is removed before it is handed to the engine. Relative indentation is kept,
+    /// but since indentation is meaningful to formats like YAML, this may still change what
+    /// some front matter means, such as a block scalar with an explicit indentation indicator.
+    /// Defaults to `false`.
+    pub dedent_matter: bool,
+    /// Whether front matter that is present but empty, like `---\n---`, is parsed into an
+    /// empty `Pod::Hash`, telling it apart from input without front matter. Otherwise, both
+    /// leave [`data`](crate::ParsedEntity::data) as `None`. The
+    /// [`matter`](crate::ParsedEntity::matter) stays empty either way. Defaults to `false`.
+    pub empty_matter_is_some: bool,
+    parse_fn: Option<Box<DynParseFn>>,
+    languages: HashMap<String, Box<DynParseFn>>,
+    content_transform: Option<Box<ContentTransform>>,
+    postprocessor: Option<Box<Postprocessor>>,
     engine: PhantomData<T>,
 }
 
+// A parse function that may be a closure, unlike the `fn` pointer `engine::ParseFn`.
+type DynParseFn = dyn Fn(&str) -> Result<Pod, EngineError> + Send + Sync;
+type ContentTransform = dyn Fn(&str) -> String + Send + Sync;
+type Postprocessor = dyn Fn(&mut Pod, &str) + Send + Sync;
+
+/// Deserializable configuration for a [`Matter`](crate::Matter), letting applications drive the
+/// parser from their own configuration files. Any field left out takes the same default as
+/// [`Matter::new`](crate::Matter::new).
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use gray_matter::{Matter, MatterConfig};
+/// # use gray_matter::engine::YAML;
+/// let config: MatterConfig = toml::from_str(r#"
+/// delimiter = "~~~"
+/// excerpt_delimiter = "<!-- more -->"
+/// "#).unwrap();
+///
+/// let matter: Matter<YAML> = Matter::from_config(config);
+/// let result = matter.parse("~~~\ntitle: Home\n~~~\nfoo\n<!-- more -->\nbar");
+///
+/// assert_eq!(result.excerpt, Some("foo".to_owned()));
+/// ```
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct MatterConfig {
+    pub delimiter: String,
+    pub close_delimiter: Option<String>,
+    pub alternate_delimiters: Vec<(String, String)>,
+    pub excerpt_delimiter: Option<String>,
+    pub excerpt_delimiters: Vec<String>,
+    pub excerpt_length: Option<usize>,
+    pub trim: bool,
+    pub content_trim_chars: Option<Vec<char>>,
+    pub strip_comments: bool,
+    pub comment_prefix: Option<String>,
+    pub code_fence: bool,
+    pub strip_bom: bool,
+    pub allow_leading_whitespace: bool,
+    pub disable_body_delimiters: bool,
+    pub key_rename: Option<KeyRename>,
+    pub coerce_bool_strings: bool,
+    pub preserve_line_endings: bool,
+    pub treat_unterminated_as_matter: bool,
+    pub require_balanced_delimiters: bool,
+    pub max_line_bytes: Option<usize>,
+    pub dedent_matter: bool,
+    pub empty_matter_is_some: bool,
+}
+
+impl Default for MatterConfig {
+    fn default() -> Self {
+        Self {
+            delimiter: "---".to_string(),
+            close_delimiter: None,
+            alternate_delimiters: vec![],
+            excerpt_delimiter: None,
+            excerpt_delimiters: vec![],
+            excerpt_length: None,
+            trim: true,
+            content_trim_chars: None,
+            strip_comments: true,
+            comment_prefix: Some("#".to_string()),
+            code_fence: false,
+            strip_bom: false,
+            allow_leading_whitespace: false,
+            disable_body_delimiters: false,
+            key_rename: None,
+            coerce_bool_strings: false,
+            preserve_line_endings: false,
+            treat_unterminated_as_matter: false,
+            require_balanced_delimiters: false,
+            max_line_bytes: None,
+            dedent_matter: false,
+            empty_matter_is_some: false,
+        }
+    }
+}
+
 impl<T: Engine> Default for Matter<T> {
     fn default() -> Self {
         Matter::new()
@@ -25,15 +261,45 @@ impl<T: Engine> Default for Matter<T> {
 
 impl<T: Engine> Matter<T> {
     pub fn new() -> Self {
+        Self::from_config(MatterConfig::default())
+    }
+
+    /// Creates a `Matter` configured by a [`MatterConfig`](crate::MatterConfig).
+    pub fn from_config(config: MatterConfig) -> Self {
         Self {
-            delimiter: "---".to_string(),
-            excerpt_delimiter: None,
+            delimiter: config.delimiter,
+            close_delimiter: config.close_delimiter,
+            alternate_delimiters: config.alternate_delimiters,
+            excerpt_delimiter: config.excerpt_delimiter,
+            excerpt_delimiters: config.excerpt_delimiters,
+            excerpt_length: config.excerpt_length,
+            trim: config.trim,
+            content_trim_chars: config.content_trim_chars,
+            strip_comments: config.strip_comments,
+            comment_prefix: config.comment_prefix,
+            code_fence: config.code_fence,
+            strip_bom: config.strip_bom,
+            allow_leading_whitespace: config.allow_leading_whitespace,
+            disable_body_delimiters: config.disable_body_delimiters,
+            key_rename: config.key_rename,
+            coerce_bool_strings: config.coerce_bool_strings,
+            preserve_line_endings: config.preserve_line_endings,
+            treat_unterminated_as_matter: config.treat_unterminated_as_matter,
+            require_balanced_delimiters: config.require_balanced_delimiters,
+            max_line_bytes: config.max_line_bytes,
+            dedent_matter: config.dedent_matter,
+            empty_matter_is_some: config.empty_matter_is_some,
+            parse_fn: None,
+            languages: HashMap::new(),
+            content_transform: None,
+            postprocessor: None,
             engine: PhantomData,
         }
     }
 
-    /// Runs parsing on the input. Uses the [engine](crate::engine) contained in `self` to parse any front matter
-    /// detected.
+    /// Sets a transform applied to the content, like de-indenting it or normalizing its line
+    /// endings, before it is stored in [`ParsedEntity::content`](crate::ParsedEntity::content).
+    /// The transform is applied after trimming, and does not affect the excerpt.
     ///
     /// ## Examples
     ///
@@ -42,96 +308,272 @@ impl<T: Engine> Matter<T> {
     /// ```rust
     /// # use gray_matter::Matter;
     /// # use gray_matter::engine::YAML;
-    /// let matter: Matter<YAML> = Matter::new();
-    /// let input = "---\ntitle: Home\n---\nOther stuff";
-    /// let parsed_entity = matter.parse(input);
+    /// let matter: Matter<YAML> = Matter::new().with_content_transform(|content| content.replace("\r\n", "\n"));
+    /// let result = matter.parse("---\ntitle: Home\n---\nOther\r\nstuff");
     ///
-    /// assert_eq!(parsed_entity.content, "Other stuff");
+    /// assert_eq!(result.content, "Other\nstuff");
     /// ```
-    pub fn parse(&self, input: &str) -> ParsedEntity {
-        // Initialize ParsedEntity
-        let mut parsed_entity = ParsedEntity {
-            data: None,
-            excerpt: None,
-            content: String::new(),
-            orig: input.to_owned(),
-            matter: String::new(),
-        };
+    pub fn with_content_transform<F>(mut self, transform: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.content_transform = Some(Box::new(transform));
+        self
+    }
 
-        // Check if input is empty or shorter than the delimiter
-        if input.is_empty() || input.len() <= self.delimiter.len() {
-            return parsed_entity;
-        }
+    /// Sets a postprocessor, which is called with the parsed front matter and the content, for
+    /// adding derived fields to the front matter, like a `slug` from the `title`. It is only
+    /// called if front matter was found, after any [content
+    /// transform](Matter::with_content_transform).
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, Pod};
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new().with_postprocessor(|data, content| {
+    ///     data["length"] = Pod::Integer(content.len() as i64);
+    /// });
+    /// let result = matter.parse("---\ntitle: Home\n---\nOther stuff");
+    ///
+    /// assert_eq!(result.data.unwrap()["length"], Pod::Integer(11));
+    /// ```
+    pub fn with_postprocessor<F>(mut self, postprocessor: F) -> Self
+    where
+        F: Fn(&mut Pod, &str) + Send + Sync + 'static,
+    {
+        self.postprocessor = Some(Box::new(postprocessor));
+        self
+    }
 
-        // If excerpt delimiter is given, use it. Otherwise, use normal delimiter
-        let excerpt_delimiter = self
-            .excerpt_delimiter
-            .clone()
-            .unwrap_or_else(|| self.delimiter.clone());
+    /// Registers `parse_fn` to parse front matter tagged with `language`, like `---ini`, or
+    /// ```` ```ini ```` if [`code_fence`](Matter::code_fence) is set. This lets formats without
+    /// a built-in engine be picked at runtime, without a `Matter` of their own. A registered
+    /// language takes precedence over a built-in engine of the same name. Languages are
+    /// matched case-insensitively.
+    ///
+    /// Like with [`with_parse_fn`](Matter::with_parse_fn), front matter that `parse_fn` fails
+    /// on gives no [`data`](crate::ParsedEntity::data) from [`parse`](Matter::parse), and makes
+    /// [`try_parse`](Matter::try_parse) return an error.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, Pod};
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new().with_language("ini", |text: &str| {
+    ///     let mut data = Pod::new_hash();
+    ///     for line in text.lines() {
+    ///         let (key, value) = line.split_once('=').ok_or("expected key=value")?;
+    ///         data[key.trim()] = Pod::String(value.trim().to_owned());
+    ///     }
+    ///     Ok::<_, &str>(data)
+    /// });
+    /// let result = matter.parse("---ini\ntitle = Home\n---\nOther stuff");
+    ///
+    /// assert_eq!(result.data.unwrap()["title"], Pod::String("Home".to_owned()));
+    /// ```
+    pub fn with_language<S, F, E>(mut self, language: S, parse_fn: F) -> Self
+    where
+        S: AsRef<str>,
+        F: Fn(&str) -> Result<Pod, E> + Send + Sync + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        self.languages.insert(
+            language.as_ref().to_lowercase(),
+            Box::new(move |content| parse_fn(content).map_err(EngineError::new)),
+        );
+        self
+    }
 
-        // If first line starts with a delimiter followed by newline, we are looking at front
-        // matter. Else, we might be looking at an excerpt.
-        let (mut looking_at, lines) = match input.split_once('\n') {
-            Some((first_line, rest)) if first_line.trim_end() == self.delimiter => {
-                (Part::Matter, rest.lines())
-            }
-            _ => (Part::MaybeExcerpt, input.lines()),
-        };
+    /// Sets [`delimiter`](Matter::delimiter). Like the other `with_` methods, it can be chained
+    /// to configure a `Matter` without mutating it afterwards.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter = Matter::<YAML>::new()
+    ///     .with_delimiter("~~~")
+    ///     .with_excerpt_delimiter("<!-- more -->");
+    /// let result = matter.parse("~~~\ntitle: Home\n~~~\nfoo\n<!-- more -->\nbar");
+    ///
+    /// assert_eq!(result.excerpt, Some("foo".to_owned()));
+    /// ```
+    pub fn with_delimiter<S: Into<String>>(mut self, delimiter: S) -> Self {
+        self.delimiter = delimiter.into();
+        self
+    }
 
-        let mut acc = String::new();
-        for line in lines {
-            line.to_string().push('\n');
-            acc += &format!("\n{}", line);
-            match looking_at {
-                Part::Matter => {
-                    if line.trim_end() == self.delimiter {
-                        let comment_re = Regex::new(r"(?m)^\s*#[^\n]+").unwrap();
-                        let matter = comment_re
-                            .replace_all(&acc, "")
-                            .trim()
-                            .strip_suffix(&self.delimiter)
-                            .expect("Could not strip front matter delimiter. You should not be able to get this message")
-                            .trim_matches('\n')
-                            .to_string();
-
-                        if !matter.is_empty() {
-                            parsed_entity.data = Some(T::parse(&matter));
-                            parsed_entity.matter = matter;
-                        }
+    /// Sets [`close_delimiter`](Matter::close_delimiter).
+    pub fn with_close_delimiter<S: Into<String>>(mut self, close_delimiter: S) -> Self {
+        self.close_delimiter = Some(close_delimiter.into());
+        self
+    }
 
-                        acc = String::new();
-                        looking_at = Part::MaybeExcerpt;
-                    }
-                }
+    /// Adds `delimiter` to the [`alternate_delimiters`](Matter::alternate_delimiters), enclosing
+    /// front matter in `language`.
+    pub fn with_alternate_delimiter<S, L>(mut self, delimiter: S, language: L) -> Self
+    where
+        S: Into<String>,
+        L: Into<String>,
+    {
+        self.alternate_delimiters
+            .push((delimiter.into(), language.into()));
+        self
+    }
 
-                Part::MaybeExcerpt => {
-                    if line.trim_end() == excerpt_delimiter {
-                        parsed_entity.excerpt = Some(
-                            acc.trim()
-                                .strip_suffix(&excerpt_delimiter)
-                                .expect("Could not strip excerpt delimiter. You should not be able to get this message")
-                                .trim_matches('\n')
-                                .to_string(),
-                        );
+    /// Sets [`excerpt_delimiter`](Matter::excerpt_delimiter).
+    pub fn with_excerpt_delimiter<S: Into<String>>(mut self, excerpt_delimiter: S) -> Self {
+        self.excerpt_delimiter = Some(excerpt_delimiter.into());
+        self
+    }
 
-                        looking_at = Part::Content;
-                    }
-                }
+    /// Sets [`excerpt_delimiters`](Matter::excerpt_delimiters).
+    pub fn with_excerpt_delimiters(mut self, excerpt_delimiters: Vec<String>) -> Self {
+        self.excerpt_delimiters = excerpt_delimiters;
+        self
+    }
 
-                Part::Content => {}
-            }
-        }
+    /// Sets [`excerpt_length`](Matter::excerpt_length).
+    pub fn with_excerpt_length(mut self, excerpt_length: usize) -> Self {
+        self.excerpt_length = Some(excerpt_length);
+        self
+    }
 
-        parsed_entity.content = acc.trim().to_string();
+    /// Sets [`trim`](Matter::trim).
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
 
-        parsed_entity
+    /// Sets [`content_trim_chars`](Matter::content_trim_chars).
+    pub fn with_content_trim_chars(mut self, chars: Vec<char>) -> Self {
+        self.content_trim_chars = Some(chars);
+        self
     }
 
-    /// Wrapper around [`parse`](Matter::parse), that deserializes any front matter into a custom
-    /// struct. Supplied as an ease-of-use function to prevent having to deserialize manually.
+    /// Sets [`strip_comments`](Matter::strip_comments).
+    pub fn with_strip_comments(mut self, strip_comments: bool) -> Self {
+        self.strip_comments = strip_comments;
+        self
+    }
+
+    /// Sets [`comment_prefix`](Matter::comment_prefix).
+    pub fn with_comment_prefix<S: Into<String>>(mut self, comment_prefix: S) -> Self {
+        self.comment_prefix = Some(comment_prefix.into());
+        self
+    }
+
+    /// Sets [`code_fence`](Matter::code_fence).
+    pub fn with_code_fence(mut self, code_fence: bool) -> Self {
+        self.code_fence = code_fence;
+        self
+    }
+
+    /// Sets [`strip_bom`](Matter::strip_bom).
+    pub fn with_strip_bom(mut self, strip_bom: bool) -> Self {
+        self.strip_bom = strip_bom;
+        self
+    }
+
+    /// Sets [`allow_leading_whitespace`](Matter::allow_leading_whitespace).
+    pub fn with_allow_leading_whitespace(mut self, allow_leading_whitespace: bool) -> Self {
+        self.allow_leading_whitespace = allow_leading_whitespace;
+        self
+    }
+
+    /// Sets [`disable_body_delimiters`](Matter::disable_body_delimiters).
+    pub fn with_disable_body_delimiters(mut self, disable_body_delimiters: bool) -> Self {
+        self.disable_body_delimiters = disable_body_delimiters;
+        self
+    }
+
+    /// Sets [`key_rename`](Matter::key_rename).
+    pub fn with_key_rename(mut self, rename: KeyRename) -> Self {
+        self.key_rename = Some(rename);
+        self
+    }
+
+    /// Sets [`coerce_bool_strings`](Matter::coerce_bool_strings).
+    pub fn with_coerce_bool_strings(mut self, coerce_bool_strings: bool) -> Self {
+        self.coerce_bool_strings = coerce_bool_strings;
+        self
+    }
+
+    /// Sets [`preserve_line_endings`](Matter::preserve_line_endings).
+    pub fn with_preserve_line_endings(mut self, preserve_line_endings: bool) -> Self {
+        self.preserve_line_endings = preserve_line_endings;
+        self
+    }
+
+    /// Sets [`treat_unterminated_as_matter`](Matter::treat_unterminated_as_matter).
+    pub fn with_treat_unterminated_as_matter(mut self, treat_unterminated_as_matter: bool) -> Self {
+        self.treat_unterminated_as_matter = treat_unterminated_as_matter;
+        self
+    }
+
+    /// Sets [`require_balanced_delimiters`](Matter::require_balanced_delimiters).
+    pub fn with_require_balanced_delimiters(mut self, require_balanced_delimiters: bool) -> Self {
+        self.require_balanced_delimiters = require_balanced_delimiters;
+        self
+    }
+
+    /// Sets [`max_line_bytes`](Matter::max_line_bytes).
+    pub fn with_max_line_bytes(mut self, max_line_bytes: usize) -> Self {
+        self.max_line_bytes = Some(max_line_bytes);
+        self
+    }
+
+    /// Sets [`dedent_matter`](Matter::dedent_matter).
+    pub fn with_dedent_matter(mut self, dedent_matter: bool) -> Self {
+        self.dedent_matter = dedent_matter;
+        self
+    }
+
+    /// Sets [`empty_matter_is_some`](Matter::empty_matter_is_some).
+    pub fn with_empty_matter_is_some(mut self, empty_matter_is_some: bool) -> Self {
+        self.empty_matter_is_some = empty_matter_is_some;
+        self
+    }
+
+    /// Runs parsing on the input. Uses the [engine](crate::engine) contained in `self` to parse any front matter
+    /// detected. Front matter the engine fails to parse gives no
+    /// [`data`](crate::ParsedEntity::data), like a single value does; use
+    /// [`try_parse`](Matter::try_parse) to get the error instead.
     ///
-    /// Returns `None` if no front matter is found, or if the front matter is not deserializable
-    /// into the custom struct.
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\n---\nOther stuff";
+    /// let parsed_entity = matter.parse(input);
+    ///
+    /// assert_eq!(parsed_entity.content, "Other stuff");
+    /// ```
+    pub fn parse(&self, input: &str) -> ParsedEntity {
+        self.parse_with_details(input, &mut ParseDetails::default())
+    }
+
+    /// Like [`parse`](Matter::parse), but borrows the text of the result from `input` instead of
+    /// copying it, for read-only use, like parsing many large files. Only the front matter is
+    /// parsed into an owned [`Pod`](crate::Pod).
+    ///
+    /// The slices keep the line endings of `input`, and the
+    /// [`matter`](crate::ParsedEntityRef::matter) is as it appears in `input`, including any
+    /// comments. Any [content transform](Matter::with_content_transform) is not applied, as it
+    /// produces new text.
     ///
     /// ## Examples
     ///
@@ -140,244 +582,3526 @@ impl<T: Engine> Matter<T> {
     /// ```rust
     /// # use gray_matter::Matter;
     /// # use gray_matter::engine::YAML;
-    /// # use gray_matter::ParsedEntityStruct;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\n---\nOther stuff";
+    /// let parsed_entity = matter.parse_borrowed(input);
+    ///
+    /// assert_eq!(parsed_entity.content, "Other stuff");
+    /// assert_eq!(parsed_entity.content.as_ptr(), input[20..].as_ptr());
+    /// ```
+    pub fn parse_borrowed<'a>(&self, input: &'a str) -> ParsedEntityRef<'a> {
+        let (parsed_entity, scanned) = self.scan(input, &mut ParseDetails::default());
+        let content = if self.trim {
+            self.trim_content(scanned.content)
+        } else {
+            scanned.content
+        };
+        let excerpt = match (scanned.excerpts.first(), self.excerpt_length) {
+            (Some(excerpt), _) => Some(*excerpt),
+            (None, Some(length)) => Some(truncate_at_word(content, length)),
+            (None, None) => None,
+        };
+        let mut data = parsed_entity.data;
+        if let (Some(postprocessor), Some(data)) = (&self.postprocessor, &mut data) {
+            postprocessor(data, content);
+        }
+
+        ParsedEntityRef {
+            data,
+            content,
+            excerpt: excerpt.filter(|excerpt| !excerpt.is_empty()),
+            orig: input,
+            matter: scanned.raw_matter.map(trim_block).unwrap_or_default(),
+        }
+    }
+
+    /// Reads all of `reader` and parses it, like [`parse`](Matter::parse). Accepts any
+    /// [`Read`](std::io::Read), like a `File` or a `&[u8]`, without having to wrap it in a
+    /// `BufReader` first.
+    ///
+    /// Returns an error if reading fails, or if the input is not valid UTF-8.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input: &[u8] = b"---\ntitle: Home\n---\nOther stuff";
+    /// let parsed_entity = matter.parse_read(input).unwrap();
+    ///
+    /// assert_eq!(parsed_entity.content, "Other stuff");
+    /// ```
+    pub fn parse_read<R: Read>(&self, reader: R) -> io::Result<ParsedEntity> {
+        let mut input = String::new();
+        BufReader::new(reader).read_to_string(&mut input)?;
+        Ok(self.parse(&input))
+    }
+
+    /// Reads the file at `path` and parses it, like [`parse`](Matter::parse).
+    ///
+    /// Returns an error if reading fails, or if the file is not valid UTF-8.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust,no_run
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let parsed_entity = matter.parse_from_path("content/index.md")?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn parse_from_path<P: AsRef<Path>>(&self, path: P) -> io::Result<ParsedEntity> {
+        let input = fs::read_to_string(path)?;
+        Ok(self.parse(&input))
+    }
+
+    /// Reads the file at `path` and deserializes its front matter into a custom struct, like
+    /// [`parse_with_struct_result`](Matter::parse_with_struct_result). Returns
+    /// [`GrayMatterError::Io`](crate::GrayMatterError::Io) if reading fails, and the same errors
+    /// as `parse_with_struct_result` otherwise.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust,no_run
+    /// # use gray_matter::{GrayMatterError, Matter};
+    /// # use gray_matter::engine::YAML;
     /// #[derive(serde::Deserialize)]
     /// struct Config {
     ///     title: String,
     /// }
     ///
     /// let matter: Matter<YAML> = Matter::new();
-    /// let input = "---\ntitle: Home\n---\nOther stuff";
-    /// let parsed_entity =  matter.parse_with_struct::<Config>(input).unwrap();
+    /// let parsed_entity = matter.parse_from_path_with_struct::<Config, _>("content/index.md")?;
+    /// println!("{}", parsed_entity.data.title);
+    /// # Ok::<(), GrayMatterError>(())
+    /// ```
+    pub fn parse_from_path_with_struct<D, P>(
+        &self,
+        path: P,
+    ) -> Result<ParsedEntityStruct<D>, GrayMatterError>
+    where
+        D: serde::de::DeserializeOwned,
+        P: AsRef<Path>,
+    {
+        let input = fs::read_to_string(path).map_err(GrayMatterError::Io)?;
+        self.parse_with_struct_result(&input)
+    }
+
+    /// Reads and parses every file in the directory `dir`, like by
+    /// [`try_parse`](Matter::try_parse), without stopping at the first failure. Returns the path
+    /// of each file, sorted, along with its parsed entity or the error it failed with, like
+    /// [`GrayMatterError::Io`](crate::GrayMatterError::Io) if it could not be read. This gives
+    /// linters a full report over a directory. Subdirectories are skipped.
     ///
-    /// assert_eq!(parsed_entity.data.title, "Home");
+    /// Returns an error if the directory cannot be listed.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust,no_run
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// for (path, result) in matter.parse_dir("content")? {
+    ///     if let Err(err) = result {
+    ///         println!("{}: {}", path.display(), err);
+    ///     }
+    /// }
+    /// # Ok::<(), std::io::Error>(())
     /// ```
-    pub fn parse_with_struct<D: serde::de::DeserializeOwned>(
+    pub fn parse_dir<P: AsRef<Path>>(
         &self,
-        input: &str,
-    ) -> Option<ParsedEntityStruct<D>> {
-        let parsed_entity = self.parse(input);
-        let data: D = parsed_entity.data?.deserialize().ok()?;
+        dir: P,
+    ) -> io::Result<Vec<(PathBuf, Result<ParsedEntity, GrayMatterError>)>> {
+        let mut paths = vec![];
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                paths.push(entry.path());
+            }
+        }
+        paths.sort();
+        Ok(paths
+            .into_iter()
+            .map(|path| {
+                let result = fs::read_to_string(&path)
+                    .map_err(GrayMatterError::Io)
+                    .and_then(|input| self.try_parse(&input));
+                (path, result)
+            })
+            .collect())
+    }
 
-        Some(ParsedEntityStruct {
-            data,
-            content: parsed_entity.content,
-            excerpt: parsed_entity.excerpt,
-            orig: parsed_entity.orig,
-            matter: parsed_entity.matter,
-        })
+    /// Like [`parse`](Matter::parse), but stricter: returns
+    /// [`GrayMatterError::EngineParse`](crate::GrayMatterError::EngineParse) if the
+    /// [engine](crate::engine) fails to parse the front matter. With
+    /// [`require_balanced_delimiters`](Matter::require_balanced_delimiters) set, also returns
+    /// [`GrayMatterError::MalformedDelimiter`](crate::GrayMatterError::MalformedDelimiter) if
+    /// the input has an opening delimiter without a closing one, which `parse` treats as content.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{GrayMatterError, Matter};
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new().with_require_balanced_delimiters(true);
+    /// let result = matter.try_parse("---\ntitle: Home\nOther stuff");
+    ///
+    /// assert_eq!(result, Err(GrayMatterError::MalformedDelimiter));
+    /// ```
+    pub fn try_parse(&self, input: &str) -> Result<ParsedEntity, GrayMatterError> {
+        let mut details = ParseDetails::default();
+        let parsed_entity = self.parse_with_details(input, &mut details);
+        if let Some(line) = details.long_line {
+            return Err(GrayMatterError::LineTooLong(line));
+        }
+        if details.unclosed_matter && self.require_balanced_delimiters {
+            return Err(GrayMatterError::MalformedDelimiter);
+        }
+        if let Some(err) = details.engine_error {
+            return Err(GrayMatterError::EngineParse(err));
+        }
+        Ok(parsed_entity)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::Matter;
-    use crate::engine::{TOML, YAML};
-    use crate::ParsedEntityStruct;
+    /// Reports the shape of `input`, like whether it has front matter, without parsing the front
+    /// matter. Cheaper than [`parse`](Matter::parse) when only an overview is needed, as the
+    /// [engine](crate::engine) is never run.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let shape = matter.inspect("---\ntitle: Home\n---\nOther stuff");
+    ///
+    /// assert!(shape.has_matter);
+    /// assert!(!shape.has_excerpt);
+    /// assert_eq!(shape.content_len, "Other stuff".len());
+    /// ```
+    pub fn inspect(&self, input: &str) -> ParseShape {
+        let mut details = ParseDetails {
+            skip_engine: true,
+            ..ParseDetails::default()
+        };
+        let parsed_entity = self.parse_with_details(input, &mut details);
 
-    #[test]
-    fn test_front_matter() {
-        #[derive(serde::Deserialize, PartialEq, Debug)]
-        struct FrontMatter {
-            abc: String,
+        ParseShape {
+            has_matter: !parsed_entity.matter.is_empty(),
+            has_excerpt: parsed_entity.excerpt.is_some(),
+            content_len: parsed_entity.content.len(),
+            matter_len: parsed_entity.matter.len(),
+        }
+    }
+
+    /// Like [`parse`](Matter::parse), but also returns how long each phase of parsing took, as
+    /// [`ParseTimings`](crate::ParseTimings). Useful for profiling whether scanning for delimiters
+    /// or the [engine](crate::engine) dominates.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\n---\nOther stuff";
+    /// let (parsed_entity, timings) = matter.parse_timed(input);
+    ///
+    /// assert_eq!(parsed_entity.content, "Other stuff");
+    /// println!("The engine took {:?}", timings.engine);
+    /// ```
+    pub fn parse_timed(&self, input: &str) -> (ParsedEntity, ParseTimings) {
+        let mut details = ParseDetails::default();
+        let parsed_entity = self.parse_with_details(input, &mut details);
+        (parsed_entity, details.timings)
+    }
+
+    /// Parses input with several front matter blocks following one another, like one per
+    /// translation, into one [`ParsedEntity`](crate::ParsedEntity) per block, in document order.
+    /// Each block has its own opening and closing delimiters. The content following the last
+    /// block is the content of the last entity, and every other entity has empty content.
+    ///
+    /// Input without front matter is parsed into a single entity, like by
+    /// [`parse`](Matter::parse).
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\nlang: en\n---\n---\nlang: fr\n---\nOther stuff";
+    /// let entities = matter.parse_many(input);
+    ///
+    /// assert_eq!(entities.len(), 2);
+    /// assert_eq!(entities[0].data.as_ref().unwrap()["lang"].as_str(), Some("en"));
+    /// assert_eq!(entities[1].data.as_ref().unwrap()["lang"].as_str(), Some("fr"));
+    /// assert_eq!(entities[1].content, "Other stuff");
+    /// ```
+    pub fn parse_many(&self, input: &str) -> Vec<ParsedEntity> {
+        self.split_blocks(input)
+            .into_iter()
+            .map(|document| self.parse(document))
+            .collect()
+    }
+
+    /// Like [`parse_many`](Matter::parse_many), but stops at the first block that
+    /// [`try_parse`](Matter::try_parse) fails on, returning its index along with the error, so
+    /// that batch importers can tell which record failed.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{GrayMatterError, Matter};
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\nlang: en\n---\n---\nlang: [fr\n---\nOther stuff";
+    ///
+    /// match matter.parse_many_result(input) {
+    ///     Err((index, GrayMatterError::EngineParse(_))) => assert_eq!(index, 1),
+    ///     result => panic!("unexpected result: {:?}", result),
+    /// }
+    /// ```
+    pub fn parse_many_result(
+        &self,
+        input: &str,
+    ) -> Result<Vec<ParsedEntity>, (usize, GrayMatterError)> {
+        self.split_blocks(input)
+            .into_iter()
+            .enumerate()
+            .map(|(index, document)| self.try_parse(document).map_err(|err| (index, err)))
+            .collect()
+    }
+
+    /// Splits input with several front matter blocks following one another into one document
+    /// per block, the last one holding the content, for [`parse_many`](Matter::parse_many).
+    fn split_blocks<'a>(&self, input: &'a str) -> Vec<&'a str> {
+        let mut documents = vec![];
+        let mut rest = input;
+        loop {
+            let content_start = self.content_start(rest);
+            let next = &rest[content_start..];
+            if content_start == 0 || self.content_start(next) == 0 {
+                documents.push(rest);
+                return documents;
+            }
+            documents.push(&rest[..content_start]);
+            rest = next;
+        }
+    }
+
+    /// Parses a feed of documents following one another, each starting with its own front
+    /// matter, like by [`parse`](Matter::parse), yielding one
+    /// [`ParsedEntity`](crate::ParsedEntity) per document as `reader` is read. Only one document
+    /// is held in memory at a time.
+    ///
+    /// A delimiter line in the content only starts a new document if it opens a block that the
+    /// engine parses into a hash, like
+    /// [`find_all_matter_blocks`](Matter::find_all_matter_blocks) requires, so that excerpt
+    /// delimiters and horizontal rules stay part of the content. Front matter in code fences is
+    /// not recognized as starting a new document.
+    ///
+    /// Yields [`GrayMatterError::Io`](crate::GrayMatterError::Io) and stops if reading fails,
+    /// or if the input is not valid UTF-8.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let feed: &[u8] = b"---\ntitle: One\n---\nFirst\n---\ntitle: Two\n---\nSecond";
+    /// let contents: Vec<String> = matter
+    ///     .parse_reader(feed)
+    ///     .map(|result| result.unwrap().content)
+    ///     .collect();
+    ///
+    /// assert_eq!(contents, vec!["First", "Second"]);
+    /// ```
+    pub fn parse_reader<'a, R: BufRead + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Iterator<Item = Result<ParsedEntity, GrayMatterError>> + 'a {
+        Documents {
+            matter: self,
+            reader,
+            looking_at: None,
+            document: String::new(),
+            candidate: String::new(),
+            lines_read: 0,
+            done: false,
+        }
+    }
+
+    /// Finds every front matter block in `input`, wherever it is, like inline metadata blocks in
+    /// a note, and parses each. Returns the byte range of each block, from the start of its
+    /// opening delimiter to the end of the line of its closing delimiter, along with its data,
+    /// in document order.
+    ///
+    /// Only blocks the engine parses into a hash are returned, so that text between two
+    /// delimiters used as horizontal rules is not mistaken for front matter.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "Newest entry\n\n---\ndate: 2024-02-01\n---\nOlder entry";
+    /// let blocks = matter.find_all_matter_blocks(input);
+    ///
+    /// assert_eq!(blocks.len(), 1);
+    /// assert_eq!(&input[blocks[0].0.clone()], "---\ndate: 2024-02-01\n---\n");
+    /// assert_eq!(blocks[0].1["date"].as_str(), Some("2024-02-01"));
+    /// ```
+    pub fn find_all_matter_blocks(&self, input: &str) -> Vec<(Range<usize>, Pod)> {
+        let mut blocks = vec![];
+        let mut start = 0;
+        while start < input.len() {
+            let rest = &input[start..];
+            let line = rest.split('\n').next().unwrap_or_default();
+            if line.starts_with(self.delimiter.as_str()) {
+                let mut details = ParseDetails::default();
+                let parsed_entity = self.parse_with_details(rest, &mut details);
+                if let (true, Some(data @ Pod::Hash(_))) =
+                    (details.content_start > 0, parsed_entity.data)
+                {
+                    blocks.push((start..start + details.content_start, data));
+                    start += details.content_start;
+                    continue;
+                }
+            }
+            start = end_of_line(input, line);
+        }
+
+        blocks
+    }
+
+    /// The byte offset in `input` right after the closing delimiter of its front matter, or `0`
+    /// if it has none. Runs no [engine](crate::engine).
+    fn content_start(&self, input: &str) -> usize {
+        let mut details = ParseDetails {
+            skip_engine: true,
+            ..ParseDetails::default()
+        };
+        self.parse_with_details(input, &mut details);
+        details.content_start
+    }
+
+    fn parse_with_details(&self, input: &str, details: &mut ParseDetails) -> ParsedEntity {
+        let start = Instant::now();
+        let (mut parsed_entity, scanned) = self.scan(input, details);
+
+        details.timings.scan = start.elapsed() - details.timings.engine;
+        let assembly_start = Instant::now();
+
+        parsed_entity.orig = input.to_owned();
+        let line_ending = if self.preserve_line_endings {
+            LineEnding::detect(input)
+        } else {
+            LineEnding::Lf
+        };
+        parsed_entity.excerpts = scanned
+            .excerpts
+            .iter()
+            .map(|excerpt| line_ending.apply(excerpt))
+            .collect();
+        parsed_entity.excerpt = parsed_entity.excerpts.first().cloned();
+        parsed_entity.excerpt_delimiter_matched =
+            scanned.excerpt_delimiter_matched.map(str::to_string);
+        if line_ending == LineEnding::Crlf {
+            parsed_entity.matter = line_ending.apply(&parsed_entity.matter);
+        }
+
+        let content = LineEnding::Lf.apply(scanned.content);
+        let content = if self.trim {
+            self.trim_content(&content)
+        } else {
+            &content
+        };
+        let content = line_ending.apply(content);
+        if let (None, Some(length)) = (&parsed_entity.excerpt, self.excerpt_length) {
+            let excerpt = truncate_at_word(&content, length);
+            if !excerpt.is_empty() {
+                parsed_entity.excerpt = Some(excerpt.to_string());
+                parsed_entity.excerpts.push(excerpt.to_string());
+            }
+        }
+        parsed_entity.content = match self.content_transform {
+            Some(ref transform) => transform(&content),
+            None => content,
+        };
+        if let (Some(postprocessor), Some(data)) = (&self.postprocessor, &mut parsed_entity.data) {
+            postprocessor(data, &parsed_entity.content);
+        }
+
+        details.timings.assembly = assembly_start.elapsed();
+
+        parsed_entity
+    }
+
+    /// Finds the parts of `input`, parsing any front matter into the returned `ParsedEntity`,
+    /// and returning the other parts as slices of `input`, with their original line endings.
+    /// The `content`, `excerpt` and `orig` of the `ParsedEntity` are left empty.
+    fn scan<'a>(&self, input: &'a str, details: &mut ParseDetails) -> (ParsedEntity, Scanned<'a>) {
+        let full_input = input;
+        let mut parsed_entity = ParsedEntity {
+            data: None,
+            excerpt: None,
+            excerpts: vec![],
+            excerpt_delimiter_matched: None,
+            stripped_comment_count: 0,
+            content: String::new(),
+            orig: String::new(),
+            matter: String::new(),
+            had_front_matter: false,
+        };
+        let mut scanned = Scanned {
+            raw_matter: None,
+            content: "",
+            excerpts: vec![],
+            excerpt_delimiter_matched: None,
+        };
+
+        // Refuse input with a line longer than allowed, before looking at any of it.
+        if let Some(max_line_bytes) = self.max_line_bytes {
+            if let Some(index) = input.lines().position(|line| line.len() > max_line_bytes) {
+                details.long_line = Some(index + 1);
+                return (parsed_entity, scanned);
+            }
+        }
+
+        // Check if input is empty or shorter than the delimiter
+        if input.is_empty() || input.len() <= self.delimiter.len() {
+            return (parsed_entity, scanned);
+        }
+
+        // Normalize the start of the input, as configured.
+        let input = match input.strip_prefix('\u{feff}') {
+            Some(rest) if self.strip_bom => rest,
+            _ => input,
+        };
+        let mut rest_of_input = if self.allow_leading_whitespace {
+            input.trim_start()
+        } else {
+            input
+        };
+
+        // Skip any directives the engine allows before the opening delimiter.
+        while let Some((line, rest)) = rest_of_input.split_once('\n') {
+            if !T::is_directive(line) {
+                break;
+            }
+            rest_of_input = rest;
+        }
+
+        // If first line starts with a delimiter followed by newline, we are looking at front
+        // matter. Else, we might be looking at an excerpt.
+        let mut opening_delimiter = self.delimiter.as_str();
+        let mut closing_delimiter = self.closing_delimiter();
+        let engine_parse_matter: engine::ParseFn = T::parse;
+        let mut tagged_parse_matter = None;
+        let mut alternate_parse_matter = None;
+        let parses_matter = T::PARSES_MATTER || self.parse_fn.is_some();
+        let mut parse_matter: &DynParseFn = match self.parse_fn {
+            Some(ref parse_fn) => parse_fn.as_ref(),
+            None => &engine_parse_matter,
+        };
+
+        // An alternate delimiter opens front matter in its own language, closed by the same
+        // delimiter.
+        let first_line = rest_of_input.split('\n').next().unwrap_or_default();
+        if !first_line.starts_with(self.delimiter.as_str()) {
+            let alternate = self
+                .alternate_delimiters
+                .iter()
+                .find(|(delimiter, _)| !delimiter.is_empty() && first_line.trim_end() == delimiter);
+            if let Some((delimiter, language)) = alternate {
+                if let Some(parse) = self.language_parse_fn(language, &mut alternate_parse_matter) {
+                    opening_delimiter = delimiter;
+                    closing_delimiter = delimiter;
+                    parse_matter = parse;
+                }
+            }
+        }
+
+        // If excerpt delimiter is given, use it. Otherwise, use the opening delimiter. Without
+        // body delimiters, no line ends an excerpt.
+        let excerpt_delimiters: Vec<&str> = std::iter::once(
+            self.excerpt_delimiter
+                .as_deref()
+                .unwrap_or(opening_delimiter),
+        )
+        .chain(self.excerpt_delimiters.iter().map(String::as_str))
+        .filter(|delimiter| !delimiter.is_empty() && !self.disable_body_delimiters)
+        .collect();
+
+        let (mut looking_at, body) = match rest_of_input.split_once('\n') {
+            Some((first_line, rest)) if first_line.trim_end() == opening_delimiter => {
+                (Part::Matter, rest)
+            }
+            // A language tag right after the opening delimiter, like `---toml`, overrides the
+            // engine.
+            Some((first_line, rest)) if first_line.starts_with(self.delimiter.as_str()) => {
+                let language = first_line[self.delimiter.len()..].trim();
+                match self.language_parse_fn(language, &mut tagged_parse_matter) {
+                    Some(parse) => {
+                        parse_matter = parse;
+                        (Part::Matter, rest)
+                    }
+                    None => (Part::MaybeExcerpt, input),
+                }
+            }
+            Some((first_line, rest)) if self.code_fence && first_line.starts_with("```") => {
+                let info_string = first_line.trim_start_matches('`');
+                let fence = &first_line[..first_line.len() - info_string.len()];
+                match self.language_parse_fn(info_string.trim(), &mut tagged_parse_matter) {
+                    Some(parse) => {
+                        closing_delimiter = fence;
+                        parse_matter = parse;
+                        (Part::Matter, rest)
+                    }
+                    None => (Part::MaybeExcerpt, input),
+                }
+            }
+            _ => (Part::MaybeExcerpt, input),
+        };
+
+        // Byte offset in the input where the current region starts: the front matter, or the
+        // content following it.
+        let mut region_start = offset_in(full_input, body);
+        // Byte offset in the input right after the last excerpt delimiter.
+        let mut excerpt_start = 0;
+        for line in body.lines() {
+            let line_start = offset_in(full_input, line);
+            match looking_at {
+                Part::Matter => {
+                    if line.trim_end() == closing_delimiter {
+                        let raw_matter = &full_input[region_start..line_start];
+                        self.read_matter(
+                            raw_matter,
+                            parses_matter.then_some(parse_matter),
+                            &mut parsed_entity,
+                            details,
+                        );
+                        scanned.raw_matter = Some(raw_matter);
+                        details.content_start = end_of_line(full_input, line);
+                        region_start = details.content_start;
+                        looking_at = Part::MaybeExcerpt;
+                    }
+                }
+
+                Part::MaybeExcerpt => {
+                    if excerpt_delimiters
+                        .iter()
+                        .any(|delimiter| is_excerpt_delimiter(line, delimiter))
+                    {
+                        let excerpt = trim_block(&full_input[region_start..line_start]);
+
+                        // A delimiter directly following the front matter is just content, and
+                        // does not make for an (empty) excerpt.
+                        if !excerpt.is_empty() {
+                            scanned.excerpts.push(excerpt);
+                            scanned.excerpt_delimiter_matched = Some(line);
+                        }
+
+                        excerpt_start = end_of_line(full_input, line);
+                        looking_at = Part::Content;
+                    } else if excerpt_delimiters
+                        .iter()
+                        .any(|delimiter| line.starts_with(delimiter))
+                        && trim_block(&full_input[region_start..line_start]).is_empty()
+                    {
+                        // Content opening with a fence-like line, like `-----`, has no excerpt
+                        // either, instead of one ending at a later delimiter.
+                        looking_at = Part::Content;
+                    }
+                }
+
+                // Following the first excerpt, every region between two successive excerpt
+                // delimiters is another excerpt.
+                Part::Content => {
+                    if scanned.excerpt_delimiter_matched.is_some()
+                        && excerpt_delimiters
+                            .iter()
+                            .any(|delimiter| is_excerpt_delimiter(line, delimiter))
+                    {
+                        let excerpt = trim_block(&full_input[excerpt_start..line_start]);
+                        if !excerpt.is_empty() {
+                            scanned.excerpts.push(excerpt);
+                        }
+                        excerpt_start = end_of_line(full_input, line);
+                    }
+                }
+            }
+        }
+
+        let rest = &full_input[region_start..];
+        scanned.content = match looking_at {
+            Part::Matter if self.treat_unterminated_as_matter => {
+                self.read_matter(
+                    rest,
+                    parses_matter.then_some(parse_matter),
+                    &mut parsed_entity,
+                    details,
+                );
+                scanned.raw_matter = Some(rest);
+                details.content_start = full_input.len();
+                ""
+            }
+            // Without a closing delimiter, the front matter is content, except for the opening
+            // delimiter, and the line ending of the last line.
+            Part::Matter => {
+                details.unclosed_matter = true;
+                rest.strip_suffix('\n')
+                    .map(|rest| rest.strip_suffix('\r').unwrap_or(rest))
+                    .unwrap_or(rest)
+            }
+            _ => rest,
+        };
+
+        (parsed_entity, scanned)
+    }
+
+    /// The delimiter closing the front matter: [`close_delimiter`](Matter::close_delimiter) if
+    /// set, and [`delimiter`](Matter::delimiter) otherwise.
+    fn closing_delimiter(&self) -> &str {
+        self.close_delimiter.as_ref().unwrap_or(&self.delimiter)
+    }
+
+    /// The parse function for front matter tagged with `language`, like `toml`: the one
+    /// registered by [`with_language`](Matter::with_language), or else that of the built-in
+    /// engine, which is stored in `built_in`.
+    fn language_parse_fn<'a>(
+        &'a self,
+        language: &str,
+        built_in: &'a mut Option<engine::ParseFn>,
+    ) -> Option<&'a DynParseFn> {
+        match self.languages.get(&language.to_lowercase()) {
+            Some(parse_fn) => Some(parse_fn.as_ref()),
+            None => {
+                *built_in = engine::by_language(language);
+                built_in.as_ref().map(|parse_fn| parse_fn as &DynParseFn)
+            }
+        }
+    }
+
+    /// Reads the front matter block `raw_matter` into `parsed_entity`, stripping comments and
+    /// indentation if configured, and parsing it with `parse_matter`, unless it is `None`.
+    fn read_matter(
+        &self,
+        raw_matter: &str,
+        parse_matter: Option<&DynParseFn>,
+        parsed_entity: &mut ParsedEntity,
+        details: &mut ParseDetails,
+    ) {
+        let raw_matter = lf(raw_matter);
+        let comment_prefix = self
+            .comment_prefix
+            .as_deref()
+            .filter(|prefix| self.strip_comments && T::STRIP_COMMENTS && !prefix.is_empty());
+        let matter: Cow<str> = match comment_prefix {
+            Some(prefix) => {
+                let (stripped, count) = strip_comment_lines(&raw_matter, prefix);
+                parsed_entity.stripped_comment_count = count;
+                unescape_comment_lines(&stripped, prefix).into()
+            }
+            None => raw_matter,
+        };
+        let matter = if self.dedent_matter {
+            dedent(&matter).into()
+        } else {
+            matter
+        };
+        let matter = matter.trim_start().trim_end_matches('\n').to_string();
+
+        if !matter.is_empty() {
+            if let Some(parse_matter) = parse_matter.filter(|_| !details.skip_engine) {
+                let engine_start = Instant::now();
+                parsed_entity.data = match parse_matter(&matter) {
+                    // A bare value, like `true`, is not structured front matter.
+                    Ok(data) if data.is_scalar() => None,
+                    Ok(data) => Some(data),
+                    // Neither is front matter the engine fails on. The error is kept for
+                    // `try_parse`.
+                    Err(mut err) => {
+                        err.render_snippet(&matter);
+                        details.engine_error = Some(err);
+                        None
+                    }
+                };
+                details.timings.engine += engine_start.elapsed();
+            }
+            parsed_entity.matter = matter;
+        } else if self.empty_matter_is_some && parse_matter.is_some() && !details.skip_engine {
+            parsed_entity.data = Some(Pod::new_hash());
+        }
+
+        parsed_entity.had_front_matter = true;
+    }
+
+    /// Wrapper around [`parse`](Matter::parse), that deserializes any front matter into a custom
+    /// struct. Supplied as an ease-of-use function to prevent having to deserialize manually.
+    ///
+    /// Returns `None` if no front matter is found, or if the front matter is not deserializable
+    /// into the custom struct.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// # use gray_matter::ParsedEntityStruct;
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     title: String,
+    /// }
+    ///
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\n---\nOther stuff";
+    /// let parsed_entity =  matter.parse_with_struct::<Config>(input).unwrap();
+    ///
+    /// assert_eq!(parsed_entity.data.title, "Home");
+    /// ```
+    pub fn parse_with_struct<D: serde::de::DeserializeOwned>(
+        &self,
+        input: &str,
+    ) -> Option<ParsedEntityStruct<D>> {
+        self.parse_with_struct_result(input).ok()
+    }
+
+    /// Like [`parse_with_struct`](Matter::parse_with_struct), but tells apart why no struct
+    /// could be returned: [`GrayMatterError::NoMatter`](crate::GrayMatterError::NoMatter) if no
+    /// front matter is found, and
+    /// [`GrayMatterError::Deserialize`](crate::GrayMatterError::Deserialize) with the message of
+    /// the serde error if the front matter is not deserializable into the custom struct.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{GrayMatterError, Matter};
+    /// # use gray_matter::engine::YAML;
+    /// #[derive(serde::Deserialize, Debug)]
+    /// struct Config {
+    ///     tags: Vec<String>,
+    /// }
+    ///
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let result = matter.parse_with_struct_result::<Config>("---\ntags: rust\n---\nOther stuff");
+    ///
+    /// assert!(matches!(result, Err(GrayMatterError::Deserialize(_))));
+    /// ```
+    pub fn parse_with_struct_result<D: serde::de::DeserializeOwned>(
+        &self,
+        input: &str,
+    ) -> Result<ParsedEntityStruct<D>, GrayMatterError> {
+        let parsed_entity = self.parse(input);
+        let pod = parsed_entity.data.ok_or(GrayMatterError::NoMatter)?;
+        let data: D = self
+            .deserialize(&pod)
+            .map_err(|err| GrayMatterError::Deserialize(err.to_string()))?;
+
+        Ok(ParsedEntityStruct {
+            data,
+            content: parsed_entity.content,
+            excerpt: parsed_entity.excerpt,
+            orig: parsed_entity.orig,
+            matter: parsed_entity.matter,
+        })
+    }
+
+    /// Like [`parse_with_struct`](Matter::parse_with_struct), but deep merges the front matter
+    /// onto `defaults` first, as by [`Pod::merge`](crate::Pod::merge), so that fields left out of
+    /// the front matter take their value from `defaults`. Input without front matter gets
+    /// `defaults` as they are.
+    ///
+    /// Returns `None` if the merged front matter is not deserializable into the custom struct.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// #[derive(serde::Deserialize, serde::Serialize)]
+    /// struct Config {
+    ///     title: String,
+    ///     layout: String,
+    /// }
+    ///
+    /// let defaults = Config {
+    ///     title: "Untitled".to_owned(),
+    ///     layout: "post".to_owned(),
+    /// };
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\n---\nOther stuff";
+    /// let parsed_entity = matter.parse_with_defaults(input, &defaults).unwrap();
+    ///
+    /// assert_eq!(parsed_entity.data.title, "Home");
+    /// assert_eq!(parsed_entity.data.layout, "post");
+    /// ```
+    pub fn parse_with_defaults<D>(&self, input: &str, defaults: &D) -> Option<ParsedEntityStruct<D>>
+    where
+        D: serde::de::DeserializeOwned + serde::Serialize,
+    {
+        let parsed_entity = self.parse(input);
+        let mut merged: Pod = serde_json::to_value(defaults).ok()?.into();
+        if let Some(pod) = parsed_entity.data {
+            merged.merge(self.renamed(&pod).into_owned());
+        }
+        let data: D = self.deserialize_renamed(&merged).ok()?;
+
+        Some(ParsedEntityStruct {
+            data,
+            content: parsed_entity.content,
+            excerpt: parsed_entity.excerpt,
+            orig: parsed_entity.orig,
+            matter: parsed_entity.matter,
+        })
+    }
+
+    /// Like [`parse_with_struct_result`](Matter::parse_with_struct_result), but lenient: if the
+    /// front matter is not deserializable into the custom struct, it is deserialized field by
+    /// field instead, falling back to the value of the field in `D::default()` for every
+    /// top-level field that fails. Returns the struct along with a
+    /// [`FieldError`](crate::FieldError) for each field that fell back, sorted by key.
+    ///
+    /// Still returns an error if no front matter is found, or if the front matter is not a hash.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// #[derive(serde::Deserialize, serde::Serialize, Default)]
+    /// struct Config {
+    ///     title: String,
+    ///     weight: i64,
+    /// }
+    ///
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\nweight: heavy\n---\nOther stuff";
+    /// let (parsed_entity, errors) = matter.parse_with_struct_lenient::<Config>(input).unwrap();
+    ///
+    /// assert_eq!(parsed_entity.data.title, "Home");
+    /// assert_eq!(parsed_entity.data.weight, 0);
+    /// assert_eq!(errors[0].field, "weight");
+    /// ```
+    pub fn parse_with_struct_lenient<D>(
+        &self,
+        input: &str,
+    ) -> Result<(ParsedEntityStruct<D>, Vec<FieldError>), GrayMatterError>
+    where
+        D: serde::de::DeserializeOwned + serde::Serialize + Default,
+    {
+        let parsed_entity = self.parse(input);
+        let pod = parsed_entity.data.ok_or(GrayMatterError::NoMatter)?;
+        let pod = self.renamed(&pod);
+        let mut errors = vec![];
+        let data: D = match self.deserialize_renamed(&pod) {
+            Ok(data) => data,
+            Err(err) => {
+                let entries = pod
+                    .entries_sorted()
+                    .ok_or_else(|| GrayMatterError::Deserialize(err.to_string()))?;
+                let mut accepted: Pod = serde_json::to_value(D::default())
+                    .map_err(|err| GrayMatterError::Deserialize(err.to_string()))?
+                    .into();
+                for (key, value) in entries {
+                    let mut candidate = accepted.clone();
+                    candidate[key.as_str()] = value.clone();
+                    match self.deserialize_renamed::<D>(&candidate) {
+                        Ok(_) => accepted = candidate,
+                        Err(err) => errors.push(FieldError {
+                            field: key.clone(),
+                            message: err.to_string(),
+                        }),
+                    }
+                }
+                self.deserialize_renamed(&accepted)
+                    .map_err(|err| GrayMatterError::Deserialize(err.to_string()))?
+            }
+        };
+
+        Ok((
+            ParsedEntityStruct {
+                data,
+                content: parsed_entity.content,
+                excerpt: parsed_entity.excerpt,
+                orig: parsed_entity.orig,
+                matter: parsed_entity.matter,
+            },
+            errors,
+        ))
+    }
+
+    /// Parses the input once and returns both views of the front matter: the data deserialized
+    /// into a custom struct, and the full [`Pod`](crate::Pod), followed by the content. Useful when
+    /// the struct only covers part of the front matter, but the remaining keys should still be
+    /// inspectable without parsing twice. The keys of the `Pod` are left as they are, even if
+    /// [`key_rename`](Matter::key_rename) is set.
+    ///
+    /// Returns `None` if no front matter is found, or if the front matter is not deserializable
+    /// into the custom struct.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, Pod};
+    /// # use gray_matter::engine::YAML;
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     title: String,
+    /// }
+    ///
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\nplugin: true\n---\nOther stuff";
+    /// let (config, pod, content) = matter.parse_dual::<Config>(input).unwrap();
+    ///
+    /// assert_eq!(config.title, "Home");
+    /// assert_eq!(pod["plugin"], Pod::Boolean(true));
+    /// assert_eq!(content, "Other stuff");
+    /// ```
+    pub fn parse_dual<D: serde::de::DeserializeOwned>(
+        &self,
+        input: &str,
+    ) -> Option<(D, Pod, String)> {
+        let parsed_entity = self.parse(input);
+        let pod = parsed_entity.data?;
+        let data: D = self.deserialize(&pod).ok()?;
+
+        Some((data, pod, parsed_entity.content))
+    }
+
+    /// Parses the input, deserializing the front matter into a custom struct, and passes the
+    /// content to `render`, like a Markdown renderer. Returns the struct along with the result
+    /// of `render`.
+    ///
+    /// Returns `None`, without calling `render`, if no front matter is found, or if the front
+    /// matter is not deserializable into the custom struct.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     title: String,
+    /// }
+    ///
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\n---\nOther stuff";
+    /// let (config, html) = matter
+    ///     .parse_and_extract::<Config, _>(input, |body| format!("<p>{}</p>", body))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(config.title, "Home");
+    /// assert_eq!(html, "<p>Other stuff</p>");
+    /// ```
+    pub fn parse_and_extract<D: serde::de::DeserializeOwned, R>(
+        &self,
+        input: &str,
+        render: impl Fn(&str) -> R,
+    ) -> Option<(D, R)> {
+        let parsed_entity = self.parse_with_struct::<D>(input)?;
+        let rendered = render(&parsed_entity.content);
+
+        Some((parsed_entity.data, rendered))
+    }
+
+    /// Like [`parse`](Matter::parse), but checks that every top-level key of the front matter is
+    /// one of `allowed`, for validating front matter against a closed set of keys without
+    /// defining a custom struct. Returns an [`UnknownKeyError`](crate::UnknownKeyError) listing
+    /// the keys that are not allowed, if any.
+    ///
+    /// Input without front matter, or with front matter that is not a hash, has no keys to
+    /// check, and is returned as it is.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\nlayuot: post\n---\nOther stuff";
+    /// let err = matter
+    ///     .parse_with_allowed_keys(input, &["title", "layout"])
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.keys, vec!["layuot"]);
+    /// ```
+    pub fn parse_with_allowed_keys(
+        &self,
+        input: &str,
+        allowed: &[&str],
+    ) -> Result<ParsedEntity, UnknownKeyError> {
+        let parsed_entity = self.parse(input);
+        if let Some(Pod::Hash(ref hash)) = parsed_entity.data {
+            let mut keys: Vec<String> = hash
+                .keys()
+                .filter(|key| !allowed.contains(&key.as_str()))
+                .cloned()
+                .collect();
+            if !keys.is_empty() {
+                keys.sort();
+                return Err(UnknownKeyError { keys });
+            }
+        }
+
+        Ok(parsed_entity)
+    }
+
+    /// Trims the content as configured by [`content_trim_chars`](Matter::content_trim_chars).
+    fn trim_content<'a>(&self, content: &'a str) -> &'a str {
+        match self.content_trim_chars {
+            Some(ref chars) => content.trim_matches(chars.as_slice()),
+            None => content.trim(),
+        }
+    }
+
+    /// Deserializes front matter into a custom struct, after converting its keys as configured
+    /// by [`key_rename`](Matter::key_rename), and coercing strings into booleans if
+    /// [`coerce_bool_strings`](Matter::coerce_bool_strings) is set.
+    fn deserialize<D: serde::de::DeserializeOwned>(&self, pod: &Pod) -> serde_json::Result<D> {
+        self.deserialize_renamed(&self.renamed(pod))
+    }
+
+    /// Front matter with its keys converted as configured by [`key_rename`](Matter::key_rename).
+    fn renamed<'a>(&self, pod: &'a Pod) -> Cow<'a, Pod> {
+        match self.key_rename {
+            Some(rename) => {
+                let mut pod = pod.clone();
+                pod.rename_keys(rename);
+                Cow::Owned(pod)
+            }
+            None => Cow::Borrowed(pod),
+        }
+    }
+
+    /// Like [`deserialize`](Matter::deserialize), for front matter that is already
+    /// [renamed](Matter::renamed).
+    fn deserialize_renamed<D: serde::de::DeserializeOwned>(
+        &self,
+        pod: &Pod,
+    ) -> serde_json::Result<D> {
+        if self.coerce_bool_strings {
+            D::deserialize(BoolStringDeserializer(pod))
+        } else {
+            pod.deserialize()
+        }
+    }
+
+    /// Removes the front matter and its delimiters from `buf` in place, reusing its allocation,
+    /// so that only the body is left. The body is trimmed as well, if [`trim`](Matter::trim) is
+    /// set, and transformed by any [content transform](Matter::with_content_transform), which
+    /// replaces the allocation. Returns the removed front matter, like
+    /// [`ParsedEntity::matter`](crate::ParsedEntity::matter), or `None` if `buf` has no front
+    /// matter, in which case it is left untouched.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let mut buf = "---\ntitle: Home\n---\nOther stuff".to_string();
+    /// let removed = matter.strip_in_place(&mut buf);
+    ///
+    /// assert_eq!(removed, Some("title: Home".to_string()));
+    /// assert_eq!(buf, "Other stuff");
+    /// ```
+    pub fn strip_in_place(&self, buf: &mut String) -> Option<String> {
+        let mut details = ParseDetails::default();
+        let parsed_entity = self.parse_with_details(buf, &mut details);
+        if details.content_start == 0 {
+            return None;
+        }
+
+        buf.replace_range(..details.content_start, "");
+        if self.trim {
+            let trimmed = self.trim_content(buf);
+            let start = trimmed.as_ptr() as usize - buf.as_ptr() as usize;
+            let end = start + trimmed.len();
+            buf.truncate(end);
+            buf.replace_range(..start, "");
+        }
+        if let Some(ref transform) = self.content_transform {
+            *buf = transform(buf);
+        }
+
+        Some(parsed_entity.matter)
+    }
+}
+
+impl Matter<Custom> {
+    /// Creates a `Matter` that parses front matter with `parse_fn` instead of an
+    /// [engine](crate::engine), for one-off formats that do not warrant implementing
+    /// [`Engine`](crate::engine::Engine). Front matter that `parse_fn` fails on gives no
+    /// [`data`](crate::ParsedEntity::data) from [`parse`](Matter::parse), like malformed front
+    /// matter with the built-in engines, and makes [`try_parse`](Matter::try_parse) return an error.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, Pod};
+    /// let matter = Matter::with_parse_fn(|text: &str| {
+    ///     let mut data = Pod::new_hash();
+    ///     for line in text.lines() {
+    ///         let (key, value) = line.split_once('=').ok_or("expected key=value")?;
+    ///         data[key.trim()] = Pod::String(value.trim().to_owned());
+    ///     }
+    ///     Ok::<_, &str>(data)
+    /// });
+    /// let result = matter.parse("---\ntitle = Home\n---\nOther stuff");
+    ///
+    /// assert_eq!(result.data.unwrap()["title"], Pod::String("Home".to_owned()));
+    /// ```
+    pub fn with_parse_fn<F, E>(parse_fn: F) -> Self
+    where
+        F: Fn(&str) -> Result<Pod, E> + Send + Sync + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self {
+            parse_fn: Some(Box::new(move |content| {
+                parse_fn(content).map_err(EngineError::new)
+            })),
+            ..Self::new()
+        }
+    }
+}
+
+impl<T: NativeEngine> Matter<T> {
+    /// Like [`parse`](Matter::parse), but returns the front matter as the value type of the
+    /// underlying parser instead of a [`Pod`](crate::Pod), for features that `Pod` flattens, like
+    /// TOML datetimes. Returns `None` if no front matter is found, or if it is malformed.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::TOML;
+    /// let matter: Matter<TOML> = Matter::new();
+    /// let input = "---\ndate = 1979-05-27T07:32:00Z\n---\nOther stuff";
+    /// let value: toml::Value = matter.parse_native(input).unwrap();
+    ///
+    /// assert!(value["date"].is_datetime());
+    /// ```
+    pub fn parse_native(&self, input: &str) -> Option<T::Value> {
+        let parsed_entity = self.parse(input);
+        if parsed_entity.matter.is_empty() {
+            return None;
+        }
+        T::parse_native(&parsed_entity.matter)
+    }
+}
+
+impl<T: Stringify> Matter<T> {
+    /// The inverse of [`parse`](Matter::parse). Writes `data` in the format of the
+    /// [engine](crate::engine) contained in `self`, enclosed by the delimiter, and followed by
+    /// `content`. Formatting is controlled by [`StringifyOptions`](crate::StringifyOptions).
+    ///
+    /// If `data` is `Pod::Null` or an empty hash, no front matter is written, and only `content`
+    /// is returned.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, StringifyOptions};
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\ntitle: Home\n---\nOther stuff";
+    /// let parsed_entity = matter.parse(input);
+    /// let document = matter
+    ///     .stringify(&parsed_entity.data.unwrap(), &parsed_entity.content, &StringifyOptions::default())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(document, input);
+    /// ```
+    pub fn stringify(
+        &self,
+        data: &Pod,
+        content: &str,
+        options: &StringifyOptions,
+    ) -> Result<String, Error> {
+        let document = match *data {
+            Pod::Null => content.to_string(),
+            Pod::Hash(ref hash) if hash.is_empty() => content.to_string(),
+            _ => format!(
+                "{}\n{}\n{}\n{}",
+                self.delimiter,
+                T::stringify(data, options)?,
+                self.closing_delimiter(),
+                content,
+            ),
+        };
+
+        Ok(options.line_ending.apply(&document))
+    }
+
+    /// Like [`stringify`](Matter::stringify), but writes any struct implementing
+    /// [`Serialize`](serde::Serialize) as the front matter. A struct with no fields, or one
+    /// serializing to `None`, writes no front matter.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, StringifyOptions};
+    /// # use gray_matter::engine::YAML;
+    /// #[derive(serde::Serialize)]
+    /// struct Config {
+    ///     title: String,
+    /// }
+    ///
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let data = Config {
+    ///     title: "Home".to_owned(),
+    /// };
+    /// let document = matter
+    ///     .stringify_with_struct(&data, "Other stuff\n", &StringifyOptions::default())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(document, "---\ntitle: Home\n---\nOther stuff\n");
+    /// ```
+    pub fn stringify_with_struct<S: serde::Serialize>(
+        &self,
+        data: &S,
+        content: &str,
+        options: &StringifyOptions,
+    ) -> Result<String, Error> {
+        let data: Pod = serde_json::to_value(data)
+            .map_err(|e| Error::serialize_error(e.to_string()))?
+            .into();
+        self.stringify(&data, content, options)
+    }
+
+    /// Writes `parsed_entity` back into a document, as the one-call round trip for editing
+    /// tools: its [`data`](crate::ParsedEntity::data) is written in the format of the
+    /// [engine](crate::engine) contained in `self`, enclosed by the delimiter, and followed by
+    /// its [`content`](crate::ParsedEntity::content), which includes any excerpt. Uses the
+    /// dominant line ending of the original input, and default
+    /// [`StringifyOptions`](crate::StringifyOptions) otherwise.
+    ///
+    /// Comments and formatting of the original front matter are not kept, nor is a language tag
+    /// on the opening delimiter.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, Pod};
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let mut parsed_entity = matter.parse("---\ntitle: Home\n---\nOther stuff");
+    /// parsed_entity.data.as_mut().unwrap()["title"] = Pod::String("About".to_owned());
+    ///
+    /// assert_eq!(
+    ///     matter.reserialize(&parsed_entity).unwrap(),
+    ///     "---\ntitle: About\n---\nOther stuff"
+    /// );
+    /// ```
+    pub fn reserialize(&self, parsed_entity: &ParsedEntity) -> Result<String, Error> {
+        let options = StringifyOptions {
+            line_ending: LineEnding::detect(&parsed_entity.orig),
+            ..StringifyOptions::default()
+        };
+        let data = parsed_entity.data.as_ref().unwrap_or(&Pod::Null);
+        self.stringify(data, &parsed_entity.content, &options)
+    }
+
+    /// Checks that the front matter of `input` survives being written by
+    /// [`stringify`](Matter::stringify) and parsed again, as a safety check before rewriting
+    /// documents. Returns [`Error::RoundtripMismatch`](crate::Error::RoundtripMismatch) with the
+    /// dotted paths of any values that came back different, like a float written as an integer.
+    /// Input without front matter trivially passes.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Error, Matter};
+    /// # use gray_matter::engine::{JSON, YAML};
+    /// let matter: Matter<YAML> = Matter::new();
+    /// assert_eq!(matter.verify_roundtrip("---\nratio: 1.0\n---"), Ok(()));
+    ///
+    /// let matter: Matter<JSON> = Matter::new();
+    /// assert_eq!(
+    ///     matter.verify_roundtrip("---\n{\"ratio\": 1.0}\n---"),
+    ///     Err(Error::RoundtripMismatch(vec!["ratio".to_owned()]))
+    /// );
+    /// ```
+    pub fn verify_roundtrip(&self, input: &str) -> Result<(), Error> {
+        let data = match self.parse(input).data {
+            Some(Pod::Hash(ref hash)) if hash.is_empty() => return Ok(()),
+            None => return Ok(()),
+            Some(data) => data,
+        };
+        let document = self.stringify(&data, "", &StringifyOptions::default())?;
+        let reparsed = self.parse(&document).data.unwrap_or(Pod::Null);
+
+        let mut paths = vec![];
+        pod::diff_paths(&data, &reparsed, "", &mut paths);
+        if paths.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::roundtrip_mismatch(paths))
+        }
+    }
+}
+
+/// Iterator over the documents of a feed, returned by
+/// [`Matter::parse_reader`](crate::Matter::parse_reader).
+struct Documents<'a, T: Engine, R> {
+    matter: &'a Matter<T>,
+    reader: R,
+    /// Where we are in the current document, or `None` before its first line. `MaybeExcerpt`
+    /// stands for reading a block that may open the next document.
+    looking_at: Option<Part>,
+    /// The lines of the current document read so far.
+    document: String,
+    /// A block starting at a delimiter line in the content, which may open the next document.
+    candidate: String,
+    /// The number of lines read from `reader` so far, counting the one being read.
+    lines_read: usize,
+    done: bool,
+}
+
+impl<'a, T: Engine, R: BufRead> Documents<'a, T, R> {
+    /// Takes the current document and parses it, leaving `next` as the start of the next one.
+    fn finish(&mut self, next: String) -> ParsedEntity {
+        let document = std::mem::replace(&mut self.document, next);
+        self.matter.parse(&document)
+    }
+
+    /// Reads the next line into `line`, like [`BufRead::read_line`], but fails instead of
+    /// reading much more of a line longer than
+    /// [`Matter::max_line_bytes`](crate::Matter::max_line_bytes).
+    fn read_line(&mut self, line: &mut String) -> Result<usize, GrayMatterError> {
+        self.lines_read += 1;
+        let max_line_bytes = match self.matter.max_line_bytes {
+            Some(max_line_bytes) => max_line_bytes,
+            None => return self.reader.read_line(line).map_err(GrayMatterError::Io),
+        };
+        let mut bytes = vec![];
+        // Room for the longest line allowed, followed by `\r\n`.
+        let limit = (max_line_bytes as u64).saturating_add(2);
+        let read = (&mut self.reader)
+            .take(limit)
+            .read_until(b'\n', &mut bytes)
+            .map_err(GrayMatterError::Io)?;
+        let text = bytes.strip_suffix(b"\n").unwrap_or(&bytes);
+        let text = text.strip_suffix(b"\r").unwrap_or(text);
+        if text.len() > max_line_bytes {
+            return Err(GrayMatterError::LineTooLong(self.lines_read));
+        }
+        let text = String::from_utf8(bytes)
+            .map_err(|err| GrayMatterError::Io(io::Error::new(io::ErrorKind::InvalidData, err)))?;
+        line.push_str(&text);
+        Ok(read)
+    }
+}
+
+impl<'a, T: Engine, R: BufRead> Iterator for Documents<'a, T, R> {
+    type Item = Result<ParsedEntity, GrayMatterError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delimiter = self.matter.delimiter.as_str();
+        let closing_delimiter = self.matter.closing_delimiter();
+        let mut line = String::new();
+        while !self.done {
+            line.clear();
+            match self.read_line(&mut line) {
+                Ok(0) => {
+                    self.done = true;
+                    // An unclosed block is just content.
+                    let candidate = std::mem::take(&mut self.candidate);
+                    self.document.push_str(&candidate);
+                    if !self.document.is_empty() {
+                        return Some(Ok(self.finish(String::new())));
+                    }
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+                Ok(_) => match self.looking_at {
+                    None => {
+                        self.document.push_str(&line);
+                        self.looking_at = Some(if line.starts_with(delimiter) {
+                            Part::Matter
+                        } else {
+                            Part::Content
+                        });
+                    }
+                    Some(Part::Matter) => {
+                        self.document.push_str(&line);
+                        if line.trim_end() == closing_delimiter {
+                            self.looking_at = Some(Part::Content);
+                        }
+                    }
+                    Some(Part::MaybeExcerpt) => self.candidate.push_str(&line),
+                    Some(Part::Content) => {
+                        if line.starts_with(delimiter) {
+                            self.candidate.push_str(&line);
+                            self.looking_at = Some(Part::MaybeExcerpt);
+                        } else {
+                            self.document.push_str(&line);
+                        }
+                    }
+                },
+            }
+            if !matches!(self.looking_at, Some(Part::MaybeExcerpt))
+                || self.candidate.len() == line.len()
+                || line.trim_end() != closing_delimiter
+            {
+                continue;
+            }
+
+            // The candidate block is closed: it either opens the next document, or is content.
+            let candidate = std::mem::take(&mut self.candidate);
+            if let Some(Pod::Hash(_)) = self.matter.parse(&candidate).data {
+                self.looking_at = Some(Part::Content);
+                return Some(Ok(self.finish(candidate)));
+            }
+            let (content, last_line) = candidate.split_at(candidate.len() - line.len());
+            self.document.push_str(content);
+            if line.starts_with(delimiter) {
+                // The closing line may open another block itself.
+                self.candidate.push_str(last_line);
+            } else {
+                self.document.push_str(last_line);
+                self.looking_at = Some(Part::Content);
+            }
+        }
+
+        None
+    }
+}
+
+/// Whether `line` of front matter is a comment: it starts with `prefix` after any indentation,
+/// followed by more text.
+fn is_comment_line(line: &str, prefix: &str) -> bool {
+    line.trim_start()
+        .strip_prefix(prefix)
+        .is_some_and(|rest| !rest.is_empty())
+}
+
+/// Matches lines opening a YAML block scalar, like `description: |` or `- >-`. Compiled once,
+/// on first use.
+fn block_scalar_re() -> &'static Regex {
+    static BLOCK_SCALAR_RE: OnceLock<Regex> = OnceLock::new();
+    BLOCK_SCALAR_RE.get_or_init(|| Regex::new(r"[:-]\s+[|>][-+0-9]*\s*(#.*)?$").unwrap())
+}
+
+/// Removes comment lines starting with `prefix` from `matter`, leaving their line endings, and
+/// returns the result along with the number of lines removed. Lines inside YAML block scalars
+/// are kept, as they are part of the value.
+fn strip_comment_lines(matter: &str, prefix: &str) -> (String, usize) {
+    let mut stripped = String::with_capacity(matter.len());
+    let mut count = 0;
+    // Indentation of the line opening the block scalar we are in, if any.
+    let mut block_indent: Option<usize> = None;
+    for (index, line) in matter.split('\n').enumerate() {
+        if index > 0 {
+            stripped.push('\n');
+        }
+        let indent = line.len() - line.trim_start().len();
+        if let Some(block_indent) = block_indent {
+            if line.trim().is_empty() || indent > block_indent {
+                stripped.push_str(line);
+                continue;
+            }
+        }
+        block_indent = None;
+        if is_comment_line(line, prefix) {
+            count += 1;
+            continue;
+        }
+        if block_scalar_re().is_match(line) {
+            block_indent = Some(indent);
+        }
+        stripped.push_str(line);
+    }
+    (stripped, count)
+}
+
+/// Removes the leading whitespace common to every line of `matter` that is not blank, keeping
+/// relative indentation. Blank lines are left empty.
+fn dedent(matter: &str) -> String {
+    let indent = matter
+        .split('\n')
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+        .fold(None, |common: Option<&str>, indent| match common {
+            None => Some(indent),
+            Some(common) => {
+                let len = common
+                    .bytes()
+                    .zip(indent.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                Some(&common[..len])
+            }
+        })
+        .unwrap_or("");
+    matter
+        .split('\n')
+        .map(|line| line.strip_prefix(indent).unwrap_or_else(|| line.trim()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Unescapes lines of `matter` starting with `\` followed by `prefix`, after any indentation,
+/// like `\#`, which are kept as `#`.
+fn unescape_comment_lines(matter: &str, prefix: &str) -> String {
+    let escaped = format!("\\{}", prefix);
+    matter
+        .split('\n')
+        .map(|line| {
+            let indent = line.len() - line.trim_start().len();
+            match line[indent..].strip_prefix(escaped.as_str()) {
+                Some(rest) => format!("{}{}{}", &line[..indent], prefix, rest),
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The start of `text`, up to `length` characters, ending at the last word boundary if that
+/// cuts a word, and without trailing whitespace. A single word longer than `length` is cut.
+fn truncate_at_word(text: &str, length: usize) -> &str {
+    let text = text.trim_start();
+    let end = match text.char_indices().nth(length) {
+        Some((end, next)) if !next.is_whitespace() => {
+            text[..end].rfind(char::is_whitespace).unwrap_or(end)
+        }
+        Some((end, _)) => end,
+        None => text.len(),
+    };
+    text[..end].trim_end()
+}
+
+/// Whether `line` matches the excerpt delimiter `delimiter`. HTML comments match regardless of
+/// the whitespace around their text.
+fn is_excerpt_delimiter(line: &str, delimiter: &str) -> bool {
+    fn comment_text(text: &str) -> Option<&str> {
+        text.strip_prefix("<!--")?
+            .strip_suffix("-->")
+            .map(str::trim)
+    }
+
+    let line = line.trim_end();
+    line == delimiter
+        || matches!(
+            (comment_text(line), comment_text(delimiter)),
+            (Some(line), Some(delimiter)) if line == delimiter
+        )
+}
+
+/// The byte offset of `part` in `input`, which it must be a slice of.
+fn offset_in(input: &str, part: &str) -> usize {
+    part.as_ptr() as usize - input.as_ptr() as usize
+}
+
+/// `text` with its `\r\n` line endings converted to `\n`, borrowed if it has none.
+fn lf(text: &str) -> Cow<'_, str> {
+    if text.contains('\r') {
+        LineEnding::Lf.apply(text).into()
+    } else {
+        text.into()
+    }
+}
+
+/// A block of lines, like an excerpt, without the blank lines and whitespace around it.
+fn trim_block(excerpt: &str) -> &str {
+    excerpt.trim_start().trim_end_matches(['\r', '\n'])
+}
+
+/// Byte offset in `input` right after `line` and its line ending. `line` must be a slice of
+/// `input`.
+fn end_of_line(input: &str, line: &str) -> usize {
+    let end = offset_in(input, line) + line.len();
+    match input[end..].find('\n') {
+        Some(index) => end + index + 1,
+        None => input.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matter;
+    use crate::engine::{NoEngine, JSON, TOML, YAML};
+    use crate::Error;
+    use crate::{GrayMatterError, KeyRename, ParsedEntityStruct, Pod};
+    use std::time::Duration;
+
+    #[test]
+    fn test_front_matter() {
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            abc: String,
         }
         let front_matter = FrontMatter {
             abc: "xyz".to_string(),
         };
         let mut matter: Matter<YAML> = Matter::new();
-        let result: ParsedEntityStruct<FrontMatter> =
-            matter.parse_with_struct("---\nabc: xyz\n---").unwrap();
+        let result: ParsedEntityStruct<FrontMatter> =
+            matter.parse_with_struct("---\nabc: xyz\n---").unwrap();
+        assert_eq!(
+            true,
+            result.data == front_matter,
+            "should get front matter as {:?}",
+            front_matter
+        );
+        matter.delimiter = "~~~".to_string();
+        let result = matter.parse("---\nabc: xyz\n---");
+        assert!(result.data.is_none(), "should get no front matter");
+        let result: ParsedEntityStruct<FrontMatter> =
+            matter.parse_with_struct("~~~\nabc: xyz\n~~~").unwrap();
+        assert_eq!(
+            result.data, front_matter,
+            "{}",
+            "should get front matter by custom delimiter"
+        );
+        let result = matter.parse("\nabc: xyz\n~~~");
+        assert!(result.data.is_none(), "should get no front matter");
+    }
+
+    #[test]
+    fn test_excerpt_length() {
+        let matter: Matter<YAML> = Matter::new().with_excerpt_length(12);
+        let result = matter.parse("---\ntitle: Home\n---\nThe quick brown fox jumps");
+        assert_eq!(result.excerpt, Some("The quick".to_string()));
+        assert_eq!(result.excerpts, vec!["The quick".to_string()]);
+        assert_eq!(result.excerpt_delimiter_matched, None);
+        assert_eq!(result.content, "The quick brown fox jumps");
+
+        let result = matter.parse("The quick brown\nfox");
+        assert_eq!(
+            result.excerpt,
+            Some("The quick".to_string()),
+            "should not end with a space"
+        );
+        let result = matter.parse("The quick b");
+        assert_eq!(result.excerpt, Some("The quick b".to_string()));
+        let result = matter.parse("Supercalifragilistic");
+        assert_eq!(result.excerpt, Some("Supercalifra".to_string()));
+        let result = matter.parse("Ünïcödé wörds everywhere");
+        assert_eq!(result.excerpt, Some("Ünïcödé".to_string()));
+        let result = matter.parse("---\ntitle: Home\n---\n");
+        assert_eq!(
+            result.excerpt, None,
+            "should get no excerpt from no content"
+        );
+
+        let result = matter.parse("---\ntitle: Home\n---\nfoo\n---\nThe quick brown fox jumps");
+        assert_eq!(
+            result.excerpt,
+            Some("foo".to_string()),
+            "should prefer an excerpt delimiter"
+        );
+    }
+
+    #[test]
+    fn test_close_delimiter() {
+        use crate::StringifyOptions;
+        let matter: Matter<YAML> = Matter::new()
+            .with_delimiter("<!--")
+            .with_close_delimiter("-->");
+        let input = "<!--\ntitle: Home\n-->\nOther stuff\n<!--\nnot matter";
+        let result = matter.parse(input);
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+        assert_eq!(result.matter, "title: Home");
+        assert_eq!(result.content, "Other stuff\n<!--\nnot matter");
+
+        let result = matter.parse("<!--\ntitle: Home\n<!--\nOther stuff");
+        assert_eq!(
+            result.data, None,
+            "should not close the front matter with the opening delimiter"
+        );
+
+        let mut data = Pod::new_hash();
+        data["title"] = Pod::String("Home".to_string());
+        assert_eq!(
+            matter.stringify(&data, "Other stuff", &StringifyOptions::default()),
+            Ok("<!--\ntitle: Home\n-->\nOther stuff".to_string())
+        );
+    }
+
+    #[test]
+    fn test_html_comment_matter() {
+        let matter: Matter<YAML> = Matter::new()
+            .with_delimiter("<!--")
+            .with_close_delimiter("-->");
+        let result = matter.parse("<!--\ntitle: Home\n-->\n<p>body</p>");
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+        assert_eq!(result.matter, "title: Home");
+        assert_eq!(result.content, "<p>body</p>");
+        assert_eq!(result.excerpt, None);
+
+        let matter = matter.with_excerpt_delimiter("<!-- more -->");
+        let result =
+            matter.parse("<!--\ntitle: Home\n-->\n<p>intro</p>\n<!-- more -->\n<p>body</p>");
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+        assert_eq!(result.excerpt, Some("<p>intro</p>".to_string()));
+        assert_eq!(result.content, "<p>intro</p>\n<!-- more -->\n<p>body</p>");
+    }
+
+    #[test]
+    pub fn test_empty_matter() {
+        let matter: Matter<YAML> = Matter::new();
+        let table = vec![
+            "---\n---\nThis is content",
+            "---\n\n---\nThis is content",
+            "---\n\n\n\n\n\n---\nThis is content",
+            "---\n # this is a comment\n# another one\n# yet another\n---\nThis is content",
+        ];
+        for input in table.into_iter() {
+            let result = matter.parse(input);
+            assert!(result.data.is_none(), "should get no front matter");
+            assert_eq!(
+                result.content, "This is content",
+                "should get content as \"This is content\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_stripped_comment_count() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let result = matter
+            .parse("---\n # this is a comment\n# another one\n# yet another\n---\nThis is content");
+        assert_eq!(
+            result.stripped_comment_count, 3,
+            "should count stripped comments"
+        );
+        matter.strip_comments = false;
+        let result = matter
+            .parse("---\n # this is a comment\n# another one\n# yet another\n---\nThis is content");
+        assert_eq!(
+            result.stripped_comment_count, 0,
+            "should count nothing without stripping"
+        );
+        matter.strip_comments = true;
+        let result = matter.parse("---\ntitle: Home\n\\# not a comment\n---");
+        assert_eq!(
+            result.stripped_comment_count, 0,
+            "should not count escaped comments"
+        );
+    }
+
+    #[test]
+    fn test_empty_matter_is_some() {
+        let mut matter: Matter<YAML> = Matter::new();
+        matter.empty_matter_is_some = true;
+        for input in [
+            "---\n---\nThis is content",
+            "---\n# a comment\n\n---\nThis is content",
+        ] {
+            let result = matter.parse(input);
+            assert_eq!(
+                result.data,
+                Some(Pod::new_hash()),
+                "should get an empty hash"
+            );
+            assert_eq!(result.matter, "");
+            assert_eq!(result.content, "This is content");
+        }
+
+        let result = matter.parse("This is content");
+        assert_eq!(
+            result.data, None,
+            "should still get nothing without a fence"
+        );
+        let result = matter.parse("---\ntitle: Home\n---");
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+
+        let matter: Matter<NoEngine> = Matter::new().with_empty_matter_is_some(true);
+        assert_eq!(matter.parse("---\n---\nThis is content").data, None);
+    }
+
+    #[test]
+    pub fn test_matter_excerpt() {
+        #[derive(serde::Deserialize, PartialEq)]
+        struct FrontMatter {
+            abc: String,
+        }
+        let mut matter: Matter<YAML> = Matter::new();
+        let result: ParsedEntityStruct<FrontMatter> = matter
+            .parse_with_struct("---\nabc: xyz\n---\nfoo\nbar\nbaz\n---\ncontent")
+            .unwrap();
+        assert_eq!(
+            result.data.abc,
+            "xyz".to_string(),
+            "should get front matter xyz as value of abc"
+        );
+        assert_eq!(
+            result.content,
+            "foo\nbar\nbaz\n---\ncontent".to_string(),
+            "should get content as \"foo\nbar\nbaz\n---\ncontent\"",
+        );
+        assert_eq!(
+            result.excerpt.unwrap(),
+            "foo\nbar\nbaz",
+            "should get an excerpt after front matter"
+        );
+        matter.excerpt_delimiter = Some("<!-- endexcerpt -->".to_string());
+        let result: ParsedEntityStruct<FrontMatter> = matter
+            .parse_with_struct("---\nabc: xyz\n---\nfoo\nbar\nbaz\n<!-- endexcerpt -->\ncontent")
+            .unwrap();
+        assert_eq!(
+            true,
+            result.data.abc == "xyz".to_string(),
+            "should get front matter xyz as value of abc"
+        );
+        assert_eq!(
+            true,
+            result.content == "foo\nbar\nbaz\n<!-- endexcerpt -->\ncontent".to_string(),
+            "should use a custom separator"
+        );
+        assert_eq!(
+            result.excerpt.unwrap(),
+            "foo\nbar\nbaz",
+            "should get excerpt as \"foo\nbar\nbaz\""
+        );
+        let result = matter.parse("foo\nbar\nbaz\n<!-- endexcerpt -->\ncontent");
+        assert!(result.data.is_none(), "should get no front matter");
+        assert_eq!(
+            true,
+            result.content == "foo\nbar\nbaz\n<!-- endexcerpt -->\ncontent".to_string(),
+            "should get content as \"foo\nbar\nbaz\n<!-- endexcerpt -->\ncontent\"",
+        );
+        assert_eq!(
+            result.excerpt.unwrap(),
+            "foo\nbar\nbaz",
+            "should use a custom separator when no front-matter exists"
+        );
+    }
+
+    #[test]
+    fn test_excerpt_delimiters() {
+        let mut matter: Matter<YAML> = Matter::new();
+        matter.excerpt_delimiter = Some("<!-- more -->".to_string());
+        matter.excerpt_delimiters = vec!["{/* more */}".to_string()];
+        for input in [
+            "foo\n<!-- more -->\nbar",
+            "foo\n<!--more-->\nbar",
+            "foo\n<!--  more\t-->\nbar",
+            "foo\n{/* more */}\nbar",
+        ]
+        .iter()
+        {
+            let result = matter.parse(input);
+            assert_eq!(
+                result.excerpt,
+                Some("foo".to_string()),
+                "should get an excerpt from {:?}",
+                input
+            );
+        }
+        assert_eq!(matter.parse("foo\n<!-- less -->\nbar").excerpt, None);
+        matter.excerpt_delimiters = vec![];
+        matter.excerpt_delimiter = Some("".to_string());
+        let result = matter.parse("foo\n\nbar\n\nbaz");
+        assert!(
+            result.excerpt.is_none(),
+            "should not get an excerpt from an empty delimiter"
+        );
+        assert_eq!(result.content, "foo\n\nbar\n\nbaz");
+    }
+
+    #[test]
+    fn test_excerpt_delimiter_matched() {
+        let matter: Matter<YAML> = Matter::new().with_excerpt_delimiter("<!-- endexcerpt -->");
+        let result = matter.parse("---\nabc: xyz\n---\nfoo\n<!-- endexcerpt -->  \ncontent");
+        assert_eq!(
+            result.excerpt_delimiter_matched,
+            Some("<!-- endexcerpt -->  ".to_string()),
+            "should capture the excerpt delimiter line verbatim"
+        );
+    }
+
+    #[test]
+    fn test_excerpt_with_leading_delimiter() {
+        let matter: Matter<YAML> = Matter::new();
+        for (input, content) in [
+            ("---\n---\n---\nfoo\n---\nbar", "---\nfoo\n---\nbar"),
+            ("-----\nfoo\n---\nbar", "-----\nfoo\n---\nbar"),
+            (
+                "---\ntitle: Home\n---\n\n-----\nfoo\n---\nbar",
+                "-----\nfoo\n---\nbar",
+            ),
+        ]
+        .iter()
+        {
+            let result = matter.parse(input);
+            assert_eq!(result.content, *content);
+            assert_eq!(
+                result.excerpt, None,
+                "should not get an excerpt from content opening with a delimiter in {:?}",
+                input
+            );
+        }
+        let result = matter.parse("---\n---\nfoo\n---\nbar");
+        assert_eq!(result.excerpt, Some("foo".to_string()));
+    }
+
+    #[test]
+    fn test_dedent_matter() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let input = "---\n    title: Home\n    tags:\n      - a\n\n    description: |\n      one\n        two\n---\nOther stuff";
+        assert_eq!(matter.parse(input).data, None);
+
+        matter.dedent_matter = true;
+        let result = matter.try_parse(input).unwrap();
+        let data = result.data.as_ref().unwrap();
+        assert_eq!(data["title"].as_string(), Ok("Home".to_string()));
+        assert_eq!(data["tags"][0].as_string(), Ok("a".to_string()));
+        assert_eq!(
+            data["description"].as_string(),
+            Ok("one\n  two".to_string()),
+            "should keep relative indentation in block scalars"
+        );
+        assert_eq!(
+            result.matter,
+            "title: Home\ntags:\n  - a\n\ndescription: |\n  one\n    two"
+        );
+
+        let result = matter.parse("---\ntitle: Home\nauthor:\n  name: Jane\n---");
+        assert_eq!(
+            result.matter, "title: Home\nauthor:\n  name: Jane",
+            "should leave unindented front matter as it is"
+        );
+    }
+
+    #[test]
+    fn test_treat_unterminated_as_matter() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let input = "---\ntitle: x\n# comment\nauthor: y\n";
+        assert_eq!(matter.parse(input).data, None);
+
+        matter.treat_unterminated_as_matter = true;
+        let result = matter.try_parse(input).unwrap();
+        let data = result.data.as_ref().unwrap();
+        assert_eq!(data["title"].as_string(), Ok("x".to_string()));
+        assert_eq!(data["author"].as_string(), Ok("y".to_string()));
+        assert_eq!(result.matter, "title: x\n\nauthor: y");
+        assert_eq!(result.content, "");
+        assert_eq!(result.stripped_comment_count, 1);
+        assert!(result.had_front_matter);
+
+        let mut buf = input.to_string();
+        assert_eq!(
+            matter.strip_in_place(&mut buf),
+            Some("title: x\n\nauthor: y".to_string())
+        );
+        assert_eq!(buf, "");
+
+        let result = matter.parse("---\ntitle: x\n---\nOther stuff");
+        assert_eq!(
+            result.content, "Other stuff",
+            "should still find a closing delimiter"
+        );
+    }
+
+    #[test]
+    fn test_content_newlines() {
+        let mut matter: Matter<YAML> = Matter::new();
+        matter.trim = false;
+        let input = "---\ntitle: Home\n---\n\nfirst\n\n\nsecond  \n\tthird\n\n";
+        let result = matter.parse(input);
+        assert_eq!(
+            result.content, "\nfirst\n\n\nsecond  \n\tthird\n\n",
+            "should keep every newline of the content"
+        );
+        let result = matter.parse("first\n\nsecond\n");
+        assert_eq!(result.content, "first\n\nsecond\n");
+        assert_eq!(matter.parse("---\ntitle: Home\n---\n").content, "");
+        assert_eq!(matter.parse("---\ntitle: Home\n---\n\n").content, "\n");
+        let result = matter.parse("---\ntitle: Home\n---\n    let x = 1;\n\n    x\n");
+        assert_eq!(
+            result.content, "    let x = 1;\n\n    x\n",
+            "should keep the indentation of the first content line"
+        );
+
+        matter.trim = true;
+        let result = matter.parse(input);
+        assert_eq!(result.content, "first\n\n\nsecond  \n\tthird");
+    }
+
+    #[test]
+    fn test_multiple_excerpts() {
+        let mut matter: Matter<YAML> = Matter::new();
+        matter.excerpt_delimiter = Some("<!-- more -->".to_string());
+        let input = "---\ntitle: Home\n---\nfirst\n<!-- more -->\nsecond\nteaser\n<!-- more -->\n<!-- more -->\nrest";
+        let result = matter.parse(input);
+        assert_eq!(result.excerpt, Some("first".to_string()));
+        assert_eq!(
+            result.excerpts,
+            vec!["first".to_string(), "second\nteaser".to_string()],
+            "should get every non-empty region between two markers"
+        );
+        assert_eq!(
+            result.content,
+            "first\n<!-- more -->\nsecond\nteaser\n<!-- more -->\n<!-- more -->\nrest"
+        );
+
+        let result = matter.parse("---\ntitle: Home\n---\nfirst\n<!-- more -->\nrest");
+        assert_eq!(result.excerpts, vec!["first".to_string()]);
+        assert!(matter.parse("no excerpt").excerpts.is_empty());
+    }
+
+    #[test]
+    fn test_parser() {
+        let matter: Matter<YAML> = Matter::new();
+        let raw = "---whatever\nabc: xyz\n---".to_string();
+        let result = matter.parse(&raw);
+        assert!(
+            result.data.is_none(),
+            "extra characters should get no front matter"
+        );
+        assert!(
+            !result.content.is_empty(),
+            "Looks similar to front matter:\n{}\nIs really just content.",
+            raw
+        );
+        let result = matter.parse("--- true\n---");
+        assert!(
+            result.data.is_none(),
+            "boolean yaml types should get no front matter"
+        );
+        let result = matter.parse("--- 233\n---");
+        assert!(
+            result.data.is_none(),
+            "number yaml types should get no front matter"
+        );
+        assert!(
+            matter.parse("").data.is_none(),
+            "Empty string should give `data` = None."
+        );
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            abc: String,
+            version: i64,
+        }
+        let result: ParsedEntityStruct<FrontMatter> = matter.parse_with_struct("---\nabc: xyz\nversion: 2\n---\n\n<span class=\"alert alert-info\">This is an alert</span>\n").unwrap();
+        let data_expected = FrontMatter {
+            abc: "xyz".to_string(),
+            version: 2,
+        };
+        assert_eq!(
+            true,
+            data_expected == result.data,
+            "should get front matter as {:?}",
+            data_expected
+        );
+        let content_expected =
+            "<span class=\"alert alert-info\">This is an alert</span>".to_string();
+        assert_eq!(
+            result.content, content_expected,
+            "should get content as {:?}",
+            content_expected
+        );
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct FrontMatterName {
+            name: String,
+        }
+        let result: ParsedEntityStruct<FrontMatterName> = matter
+            .parse_with_struct(
+                r#"---
+name: "troublesome --- value"
+---
+here is some content
+"#,
+            )
+            .unwrap();
+        let data_expected = FrontMatterName {
+            name: "troublesome --- value".to_string(),
+        };
+        assert_eq!(
+            true,
+            result.data == data_expected,
+            "should correctly identify delimiters and ignore strings that look like delimiters and get front matter as {:?}", data_expected
+        );
+        let result: ParsedEntityStruct<FrontMatterName> = matter
+            .parse_with_struct("---\nname: \"troublesome --- value\"\n---")
+            .unwrap();
+        assert_eq!(
+            true,
+            result.data == data_expected,
+            "should correctly parse a string that only has an opening delimiter and get front matter as {:?}", data_expected
+        );
+        let result = matter.parse("-----------name--------------value\nfoo");
+        assert!(
+            result.data.is_none(),
+            "should not try to parse a string has content that looks like front-matter"
+        );
+        let result = matter.parse("---\nname: ---\n---\n---\n");
+        assert_eq!(
+            result.content, "---",
+            "should correctly handle rogue delimiter"
+        );
+        let result = matter.parse("---\nname: bar\n---\n---\n---");
+        assert_eq!(
+            result.content, "---\n---",
+            "should correctly handle two rogue delimiter"
+        );
+    }
+
+    #[test]
+    fn test_scalar_matter() {
+        let matter: Matter<YAML> = Matter::new();
+        for raw in [
+            "---\ntrue\n---",
+            "---\n233\n---",
+            "---\njust a string\n---",
+            "---\n~\n---",
+        ] {
+            let result = matter.parse(raw);
+            assert_eq!(
+                result.data, None,
+                "should reject scalar front matter in {:?}",
+                raw
+            );
+            assert!(result.had_front_matter);
+            assert_eq!(result.content, "");
+        }
+        let result = matter.parse("---\n- a\n---");
+        assert_eq!(
+            result.data,
+            Some(Pod::Array(vec![Pod::String("a".to_string())])),
+            "should keep an array"
+        );
+        let json_matter: Matter<JSON> = Matter::new();
+        assert_eq!(json_matter.parse("---\n\"a string\"\n---").data, None);
+        assert_eq!(json_matter.parse("---\n42\n---").data, None);
+        let toml_matter: Matter<TOML> = Matter::new();
+        assert!(
+            matches!(
+                toml_matter.try_parse("---\ntrue\n---"),
+                Err(GrayMatterError::EngineParse(_))
+            ),
+            "should be malformed TOML"
+        );
+        assert_eq!(toml_matter.parse("---\ntrue\n---").data, None);
+    }
+
+    #[test]
+    fn test_rogue_delimiters_excerpt() {
+        let matter: Matter<YAML> = Matter::new();
+        let result = matter.parse("---\nname: bar\n---\n---\n---");
+        assert_eq!(result.content, "---\n---");
+        assert!(
+            result.excerpt.is_none(),
+            "should not get an empty excerpt from rogue delimiters"
+        );
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let result = matter
+            .try_parse("---\ntitle: Home\n---\nOther stuff")
+            .unwrap();
+        assert_eq!(result, matter.parse("---\ntitle: Home\n---\nOther stuff"));
+        assert_eq!(matter.try_parse("Other stuff").unwrap().data, None);
+        let unbalanced = "---\ntitle: Home\nOther stuff";
+        assert_eq!(
+            matter.try_parse(unbalanced),
+            Ok(matter.parse(unbalanced)),
+            "should treat an unclosed front matter as content by default"
+        );
+        matter.require_balanced_delimiters = true;
+        assert_eq!(
+            matter.try_parse(unbalanced),
+            Err(GrayMatterError::MalformedDelimiter),
+            "should fail on an unclosed front matter"
+        );
+        assert!(matter
+            .try_parse("---\ntitle: Home\n---\nOther stuff")
+            .is_ok());
+        matter.require_balanced_delimiters = false;
+        match matter.try_parse("---\ntitle: [Home\n---\nOther stuff") {
+            Err(GrayMatterError::EngineParse(err)) => assert!(
+                err.downcast_ref::<yaml_rust::ScanError>().is_some(),
+                "should hold the error of the YAML parser"
+            ),
+            result => panic!("should fail on malformed front matter, got {:?}", result),
+        }
+        let snippet = "2 | name: @x\n  |       ^";
+        let err = matter
+            .try_parse("---\ntitle: Home\nname: @x\n---\nOther stuff")
+            .unwrap_err();
+        assert!(
+            err.to_string().ends_with(snippet),
+            "should show the snippet, got {:?}",
+            err.to_string()
+        );
+        match err {
+            GrayMatterError::EngineParse(err) => {
+                assert_eq!(err.position(), Some((2, 7)));
+                assert_eq!(err.snippet(), Some(snippet));
+            }
+            err => panic!("should fail on malformed front matter, got {:?}", err),
+        }
+        assert_eq!(
+            matter.parse("---\ntitle: [Home\n---\nOther stuff").data,
+            None,
+            "should get no data from parse"
+        );
+
+        // A delimiter that looks like a comment is not stripped as one.
+        matter.delimiter = "###".to_string();
+        let result = matter
+            .try_parse("###\ntitle: Home\n# comment\n###\nOther stuff")
+            .unwrap();
+        assert_eq!(result.matter, "title: Home");
+        assert_eq!(result.content, "Other stuff");
+    }
+
+    #[test]
+    fn test_parse_with_struct_result() {
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            title: String,
+            tags: Vec<String>,
+        }
+        let matter: Matter<YAML> = Matter::new();
+        let result = matter
+            .parse_with_struct_result::<FrontMatter>(
+                "---\ntitle: Home\ntags: [a]\n---\nOther stuff",
+            )
+            .unwrap();
+        assert_eq!(result.data.tags, vec!["a".to_string()]);
+        assert_eq!(
+            matter.parse_with_struct_result::<FrontMatter>("Other stuff"),
+            Err(GrayMatterError::NoMatter)
+        );
+        match matter.parse_with_struct_result::<FrontMatter>("---\ntitle: Home\ntags: a\n---") {
+            Err(GrayMatterError::Deserialize(message)) => assert!(
+                message.contains("invalid type"),
+                "should carry the serde error, got {:?}",
+                message
+            ),
+            result => panic!(
+                "should fail to deserialize, got {:?}",
+                result.map(|r| r.data)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_parse_with_defaults() {
+        #[derive(serde::Deserialize, serde::Serialize, PartialEq, Debug, Clone)]
+        struct Author {
+            name: String,
+            email: Option<String>,
+        }
+        #[derive(serde::Deserialize, serde::Serialize, PartialEq, Debug, Clone)]
+        struct FrontMatter {
+            title: String,
+            draft: bool,
+            tags: Vec<String>,
+            author: Author,
+        }
+        let defaults = FrontMatter {
+            title: "Untitled".to_string(),
+            draft: true,
+            tags: vec!["misc".to_string()],
+            author: Author {
+                name: "Jane".to_string(),
+                email: Some("jane@example.com".to_string()),
+            },
+        };
+        let matter: Matter<YAML> = Matter::new();
+        let input = "---\ntitle: Home\ntags: [a]\nauthor:\n  name: John\n---\nOther stuff";
+        let result = matter.parse_with_defaults(input, &defaults).unwrap();
+        assert_eq!(
+            result.data,
+            FrontMatter {
+                title: "Home".to_string(),
+                draft: true,
+                tags: vec!["a".to_string()],
+                author: Author {
+                    name: "John".to_string(),
+                    email: Some("jane@example.com".to_string()),
+                },
+            },
+            "should override present fields and keep defaults for omitted ones"
+        );
+        assert_eq!(result.content, "Other stuff");
+
+        let result = matter
+            .parse_with_defaults("Other stuff", &defaults)
+            .unwrap();
+        assert_eq!(
+            result.data, defaults,
+            "should get the defaults without front matter"
+        );
+
+        assert!(matter
+            .parse_with_defaults("---\ndraft: maybe\n---", &defaults)
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_with_struct_lenient() {
+        #[derive(serde::Deserialize, serde::Serialize, PartialEq, Debug, Default)]
+        struct FrontMatter {
+            title: String,
+            weight: i64,
+            ratio: f64,
+            tags: Vec<String>,
+        }
+        let matter: Matter<YAML> = Matter::new();
+        let input = "---\ntitle: Home\nweight: heavy\nratio: 0.5\ntags: [a, b]\n---\nOther stuff";
+        assert!(matter.parse_with_struct::<FrontMatter>(input).is_none());
+
+        let (result, errors) = matter
+            .parse_with_struct_lenient::<FrontMatter>(input)
+            .unwrap();
+        assert_eq!(
+            result.data,
+            FrontMatter {
+                title: "Home".to_string(),
+                weight: 0,
+                ratio: 0.5,
+                tags: vec!["a".to_string(), "b".to_string()],
+            },
+            "should fall back to the default of the bad field only"
+        );
+        assert_eq!(result.content, "Other stuff");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "weight");
+
+        let (result, errors) = matter
+            .parse_with_struct_lenient::<FrontMatter>("---\ntitle: Home\n---")
+            .unwrap();
+        assert_eq!(result.data.title, "Home");
+        assert_eq!(errors, vec![], "should fill in missing fields silently");
+
+        assert_eq!(
+            matter
+                .parse_with_struct_lenient::<FrontMatter>("Other stuff")
+                .map(|(r, _)| r.data),
+            Err(GrayMatterError::NoMatter)
+        );
+        assert!(matches!(
+            matter.parse_with_struct_lenient::<FrontMatter>("---\n- a\n---"),
+            Err(GrayMatterError::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_and_extract() {
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            title: String,
+        }
+        let matter: Matter<YAML> = Matter::new();
+        let (data, len) = matter
+            .parse_and_extract::<FrontMatter, _>("---\ntitle: Home\n---\nOther stuff", str::len)
+            .unwrap();
+        assert_eq!(
+            data,
+            FrontMatter {
+                title: "Home".to_string()
+            }
+        );
+        assert_eq!(len, "Other stuff".len());
+
+        assert!(matter
+            .parse_and_extract::<FrontMatter, _>("no front matter", |_| -> () {
+                panic!("should not render without front matter")
+            })
+            .is_none());
+        assert!(matter
+            .parse_and_extract::<FrontMatter, _>("---\nlayout: post\n---\nfoo", str::len)
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_with_allowed_keys() {
+        let matter: Matter<YAML> = Matter::new();
+        let allowed = ["title", "tags"];
+
+        let result = matter
+            .parse_with_allowed_keys("---\ntitle: Home\ntags: [a]\n---\nOther stuff", &allowed)
+            .unwrap();
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+        assert_eq!(result.content, "Other stuff");
+
+        let err = matter
+            .parse_with_allowed_keys("---\ntitle: Home\nlayout: post\ndraft: true\n---", &allowed)
+            .unwrap_err();
+        assert_eq!(err.keys, vec!["draft".to_string(), "layout".to_string()]);
+        assert_eq!(err.to_string(), "Unknown front matter keys: draft, layout");
+
+        assert!(
+            matter
+                .parse_with_allowed_keys("---\nauthor:\n  name: Jane\n---", &["author"])
+                .is_ok(),
+            "should only check top-level keys"
+        );
+        assert!(matter
+            .parse_with_allowed_keys("no front matter", &allowed)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let matter: Matter<YAML> = Matter::new();
+        let input = "---\nlang: en\n---\n---\nlang: fr\n---\n---\nlang: de\n---\nfoo\n---\nbar";
+        let entities = matter.parse_many(input);
+        let langs: Vec<_> = entities
+            .iter()
+            .map(|entity| entity.data.as_ref().unwrap()["lang"].as_string().unwrap())
+            .collect();
+        assert_eq!(langs, vec!["en", "fr", "de"]);
+        assert_eq!(entities[0].content, "");
+        assert_eq!(entities[0].matter, "lang: en");
+        assert_eq!(entities[1].content, "");
+        assert_eq!(
+            entities[2].content, "foo\n---\nbar",
+            "should not read a delimiter in the content as another block"
+        );
+        assert_eq!(entities[2].excerpt, Some("foo".to_string()));
+
+        let entities = matter.parse_many("---\nlang: en\n---\nOther stuff");
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0], matter.parse("---\nlang: en\n---\nOther stuff"));
+
+        let entities = matter.parse_many("no front matter");
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].data, None);
+        assert_eq!(entities[0].content, "no front matter");
+    }
+
+    #[test]
+    fn test_parse_many_alternate_delimiters() {
+        let matter: Matter<YAML> = Matter::new().with_alternate_delimiter("+++", "toml");
+        let input = "---\nlang: en\n---\n+++\nlang = \"fr\"\n+++\nfoo\n+++\nbar";
+        let entities = matter.parse_many(input);
+        assert_eq!(entities.len(), 2);
+        assert_eq!(
+            entities[0].data.as_ref().unwrap()["lang"].as_str(),
+            Some("en")
+        );
+        assert_eq!(entities[0].content, "");
+        assert_eq!(
+            entities[1].data.as_ref().unwrap()["lang"].as_str(),
+            Some("fr"),
+            "should parse the block with the engine of its delimiter"
+        );
+        assert_eq!(entities[1].matter, "lang = \"fr\"");
+        assert_eq!(entities[1].content, "foo\n+++\nbar");
+        assert_eq!(
+            entities[1].excerpt,
+            Some("foo".to_string()),
+            "should end the excerpt at the delimiter of the block"
+        );
+
+        let result = matter.parse("+++\ntitle = \"Home\"\n---\nOther stuff");
+        assert_eq!(
+            result.data, None,
+            "should not close the front matter with another delimiter"
+        );
+        let matter: Matter<YAML> = Matter::new().with_alternate_delimiter("+++", "rust");
+        let result = matter.parse("+++\ntitle = \"Home\"\n+++\nOther stuff");
+        assert_eq!(result.data, None, "should skip an unknown language");
+    }
+
+    #[test]
+    fn test_parse_many_result() {
+        let matter: Matter<YAML> = Matter::new();
+        let input = "---\nlang: en\n---\n---\nlang: fr\n---\nOther stuff";
+        assert_eq!(
+            matter.parse_many_result(input),
+            Ok(matter.parse_many(input))
+        );
+
+        let input = "---\nlang: en\n---\n---\nlang: [fr\n---\n---\nlang: [de\n---\nOther stuff";
+        match matter.parse_many_result(input) {
+            Err((index, GrayMatterError::EngineParse(err))) => {
+                assert_eq!(index, 1, "should report the first invalid block");
+                assert!(err.downcast_ref::<yaml_rust::ScanError>().is_some());
+            }
+            result => panic!("should fail on invalid YAML, got {:?}", result),
+        }
+        assert_eq!(
+            matter.parse_many(input)[1].data,
+            None,
+            "should still parse every block with parse_many"
+        );
+    }
+
+    #[test]
+    fn test_parse_borrowed() {
+        let matter: Matter<YAML> = Matter::new();
+        let inputs = [
+            "---\ntitle: Home\n---\nOther stuff",
+            "---\ntitle: Home\n---\n\nAn excerpt\n---\nOther stuff\n",
+            "---\n---\nOther stuff",
+            "---\ntitle: Home\nOther stuff",
+            "No front matter\n---\nOther stuff",
+            "",
+        ];
+        for input in inputs.iter() {
+            let borrowed = matter.parse_borrowed(input);
+            let owned = matter.parse(input);
+            assert_eq!(borrowed.data, owned.data, "{:?}", input);
+            assert_eq!(borrowed.content, owned.content, "{:?}", input);
+            assert_eq!(borrowed.excerpt, owned.excerpt.as_deref(), "{:?}", input);
+            assert_eq!(borrowed.matter, owned.matter, "{:?}", input);
+            assert_eq!(borrowed.orig, owned.orig);
+        }
+
+        let input = "---\r\ntitle: Home # the title\r\n# a comment\r\n---\r\nfoo\r\n---\r\nbar\r\n";
+        let result = matter.parse_borrowed(input);
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+        assert_eq!(
+            result.matter, "title: Home # the title\r\n# a comment",
+            "should keep comments and line endings"
+        );
+        assert_eq!(result.excerpt, Some("foo"));
+        assert_eq!(result.content, "foo\r\n---\r\nbar");
+        assert_eq!(result.orig.as_ptr(), input.as_ptr());
+
+        let matter: Matter<YAML> = Matter::new().with_trim(false).with_excerpt_length(5);
+        let result = matter.parse_borrowed("---\ntitle: Home\n---\n\nOther stuff\n");
+        assert_eq!(result.content, "\nOther stuff\n");
+        assert_eq!(result.excerpt, Some("Other"));
+    }
+
+    #[test]
+    fn test_max_line_bytes() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let long_line = "a".repeat(1 << 20);
+        let input = format!("---\ntitle: Home\n---\n{}\nOther stuff", long_line);
+        assert!(matter.try_parse(&input).is_ok());
+
+        matter.max_line_bytes = Some(1024);
+        assert_eq!(
+            matter.try_parse(&input),
+            Err(GrayMatterError::LineTooLong(4))
+        );
+        let result = matter.parse(&input);
+        assert_eq!(result.data, None);
+        assert_eq!(result.content, "");
+        let input = format!(
+            "---\ntitle: Home\n---\n{}\r\nOther stuff",
+            &long_line[..1024]
+        );
+        assert!(
+            matter.try_parse(&input).is_ok(),
+            "should allow a line of the maximum length"
+        );
+
+        let feed = format!(
+            "---\ntitle: One\n---\nFirst\n---\ntitle: Two\n---\n{}",
+            long_line
+        );
+        let mut results = matter.parse_reader(feed.as_bytes());
+        assert_eq!(results.next().unwrap().unwrap().content, "First");
+        assert_eq!(results.next(), Some(Err(GrayMatterError::LineTooLong(8))));
+        assert!(results.next().is_none(), "should stop after an error");
+        let mut results = matter.parse_reader(std::io::BufReader::new(std::io::repeat(b'a')));
+        assert_eq!(
+            results.next(),
+            Some(Err(GrayMatterError::LineTooLong(1))),
+            "should not read an endless line"
+        );
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let matter: Matter<YAML> = Matter::new();
+        let documents = [
+            "---\ntitle: One\n---\nAn excerpt\n---\nFirst\n\n---\nnot: [metadata\n---\n",
+            "---\ntitle: Two\n---\nSecond\n---\n",
+            "---\ntitle: Three\n---\nThird\n",
+        ];
+        let feed = documents.concat();
+        let results: Vec<_> = matter
+            .parse_reader(feed.as_bytes())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(results.len(), 3);
+        for (result, document) in results.iter().zip(documents.iter()) {
+            assert_eq!(result, &matter.parse(document));
+        }
+        assert_eq!(results[0].excerpt, Some("An excerpt".to_string()));
+        assert_eq!(
+            results[0].content, "An excerpt\n---\nFirst\n\n---\nnot: [metadata\n---",
+            "should keep blocks that are not front matter in the content"
+        );
+        assert_eq!(results[1].content, "Second\n---");
+        assert_eq!(
+            results[2].data.as_ref().unwrap()["title"].as_str(),
+            Some("Three")
+        );
+
+        let results: Vec<_> = matter
+            .parse_reader("Just content\n---\ntitle: Home\n---\nOther stuff".as_bytes())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].data, None);
+        assert_eq!(results[0].content, "Just content");
+        assert_eq!(results[1].content, "Other stuff");
+
+        assert_eq!(matter.parse_reader("".as_bytes()).count(), 0);
+
+        let mut results = matter.parse_reader(&b"---\ntitle: \xff\n---"[..]);
+        assert!(matches!(results.next(), Some(Err(GrayMatterError::Io(_)))));
+        assert!(results.next().is_none(), "should stop after an error");
+    }
+
+    #[test]
+    fn test_find_all_matter_blocks() {
+        let matter: Matter<YAML> = Matter::new();
+        let input = "Newest entry\n\n---\ndate: 2024-02-02\n---\nNewer entry\n---\nnot metadata\n---\n\n---\ndate: 2024-02-01\ntags: [a]\n---\nOlder entry";
+        let blocks = matter.find_all_matter_blocks(input);
+        assert_eq!(blocks.len(), 2);
+
+        let (ref range, ref data) = blocks[0];
+        assert_eq!(&input[range.clone()], "---\ndate: 2024-02-02\n---\n");
+        assert_eq!(range.start, "Newest entry\n\n".len());
+        assert_eq!(data["date"].as_str(), Some("2024-02-02"));
+
+        let (ref range, ref data) = blocks[1];
+        assert_eq!(
+            &input[range.clone()],
+            "---\ndate: 2024-02-01\ntags: [a]\n---\n"
+        );
+        assert_eq!(data["tags"][0].as_str(), Some("a"));
+        assert!(input[range.end..].starts_with("Older entry"));
+
+        let input = "---\ntitle: Home\n---";
+        assert_eq!(
+            matter.find_all_matter_blocks(input),
+            vec![(0..input.len(), matter.parse(input).data.unwrap())],
+            "should find a block at the very start"
+        );
+        assert!(matter.find_all_matter_blocks("No metadata").is_empty());
+        assert!(matter
+            .find_all_matter_blocks("foo\n---\ntitle: Home")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_dual() {
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            title: String,
+        }
+        let matter: Matter<YAML> = Matter::new();
+        let input = "---\ntitle: Home\nlayout: post\n---\nOther stuff";
+        let (data, pod, content) = matter.parse_dual::<FrontMatter>(input).unwrap();
+        let result = matter.parse(input);
+        assert_eq!(
+            data,
+            FrontMatter {
+                title: "Home".to_string()
+            }
+        );
+        assert_eq!(
+            Some(pod.clone()),
+            result.data,
+            "should get the full front matter"
+        );
+        assert_eq!(
+            pod["title"].as_string(),
+            Ok(data.title),
+            "typed and dynamic views should agree"
+        );
+        assert_eq!(pod["layout"].as_string(), Ok("post".to_string()));
+        assert_eq!(content, result.content);
+        assert!(matter
+            .parse_dual::<FrontMatter>("no front matter")
+            .is_none());
+    }
+
+    #[test]
+    fn test_from_config() {
+        use super::MatterConfig;
+        let config: MatterConfig = toml::from_str(
+            r#"
+delimiter = "~~~"
+excerpt_delimiter = "<!-- endexcerpt -->"
+trim = false
+strip_comments = false
+key_rename = "snake_case"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            MatterConfig {
+                delimiter: "~~~".to_string(),
+                close_delimiter: None,
+                alternate_delimiters: vec![],
+                excerpt_delimiter: Some("<!-- endexcerpt -->".to_string()),
+                excerpt_delimiters: vec![],
+                excerpt_length: None,
+                trim: false,
+                content_trim_chars: None,
+                strip_comments: false,
+                comment_prefix: Some("#".to_string()),
+                code_fence: false,
+                strip_bom: false,
+                allow_leading_whitespace: false,
+                disable_body_delimiters: false,
+                key_rename: Some(KeyRename::SnakeCase),
+                coerce_bool_strings: false,
+                preserve_line_endings: false,
+                treat_unterminated_as_matter: false,
+                require_balanced_delimiters: false,
+                max_line_bytes: None,
+                dedent_matter: false,
+                empty_matter_is_some: false,
+            }
+        );
+        let matter: Matter<YAML> = Matter::from_config(config);
+        let result =
+            matter.parse("~~~\nabc: |\n  # xyz\n~~~\nfoo\n<!-- endexcerpt -->\n  content\n");
+        assert_eq!(
+            result.data.unwrap()["abc"].as_string(),
+            Ok("# xyz".to_string()),
+            "should keep lines starting with `#` when comment stripping is disabled"
+        );
+        assert_eq!(result.excerpt, Some("foo".to_string()));
+        assert_eq!(result.content, "foo\n<!-- endexcerpt -->\n  content\n");
+
+        let config: MatterConfig = toml::from_str("delimiter = \"+++\"").unwrap();
+        assert_eq!(
+            config.excerpt_delimiter, None,
+            "should use defaults for missing fields"
+        );
+        assert!(config.trim);
+        assert!(config.strip_comments);
+        assert_eq!(config.comment_prefix, Some("#".to_string()));
+    }
+
+    #[test]
+    fn test_key_rename() {
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            page_title: String,
+        }
+        let mut matter: Matter<YAML> = Matter::new();
+        let input = "---\npageTitle: Home\n---\nOther stuff";
+        assert!(matter.parse_with_struct::<FrontMatter>(input).is_none());
+
+        matter.key_rename = Some(KeyRename::SnakeCase);
+        let result: ParsedEntityStruct<FrontMatter> = matter.parse_with_struct(input).unwrap();
+        assert_eq!(result.data.page_title, "Home");
+        let (data, pod, _) = matter.parse_dual::<FrontMatter>(input).unwrap();
+        assert_eq!(data.page_title, "Home");
+        assert_eq!(pod["pageTitle"].as_string(), Ok("Home".to_string()));
+    }
+
+    #[test]
+    fn test_coerce_bool_strings() {
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        #[serde(rename_all = "lowercase")]
+        enum Layout {
+            Post,
+            Page,
+        }
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct Feed {
+            enabled: bool,
+        }
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct FrontMatter {
+            title: String,
+            draft: bool,
+            published: Option<bool>,
+            flags: Vec<bool>,
+            feed: Feed,
+            layout: Layout,
+        }
+        let mut matter: Matter<YAML> = Matter::new();
+        let input = "---\ntitle: \"yes\"\ndraft: \"yes\"\npublished: \"False\"\nflags: [\"no\", true]\nfeed:\n  enabled: \"true\"\nlayout: post\n---\nOther stuff";
+        assert!(
+            matter.parse_with_struct::<FrontMatter>(input).is_none(),
+            "should not coerce by default"
+        );
+
+        matter.coerce_bool_strings = true;
+        let result: ParsedEntityStruct<FrontMatter> = matter.parse_with_struct(input).unwrap();
+        assert_eq!(
+            result.data,
+            FrontMatter {
+                title: "yes".to_string(),
+                draft: true,
+                published: Some(false),
+                flags: vec![false, true],
+                feed: Feed { enabled: true },
+                layout: Layout::Post,
+            },
+            "should only coerce strings where a bool is expected"
+        );
+
+        let input =
+            "---\ntitle: Home\ndraft: maybe\nflags: []\nfeed: {enabled: no}\nlayout: page\n---";
+        assert!(
+            matter.parse_with_struct::<FrontMatter>(input).is_none(),
+            "should fail on strings that are not booleans"
+        );
+    }
+
+    #[test]
+    fn test_parse_read() {
+        let matter: Matter<YAML> = Matter::new();
+        let input: &[u8] = b"---\ntitle: Home\n---\nOther stuff";
+        let result = matter.parse_read(input).unwrap();
+        assert_eq!(
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string())
+        );
+        assert_eq!(result.content, "Other stuff");
+
+        let input: &[u8] = b"---\ntitle: \xff\n---";
+        assert_eq!(
+            matter.parse_read(input).unwrap_err().kind(),
+            std::io::ErrorKind::InvalidData,
+            "should fail on invalid UTF-8"
+        );
+    }
+
+    #[test]
+    fn test_with_parse_fn() {
+        let matter = Matter::with_parse_fn(|text: &str| {
+            let mut data = Pod::new_hash();
+            for line in text.lines() {
+                let (key, value) = line.split_once('=').ok_or("expected key=value")?;
+                data[key.trim()] = Pod::String(value.trim().to_string());
+            }
+            Ok::<_, &str>(data)
+        });
+        let result = matter.parse("---\ntitle = Home\nlayout = post\n---\nOther stuff");
+        let data = result.data.unwrap();
+        assert_eq!(data["title"], Pod::String("Home".to_string()));
+        assert_eq!(data["layout"], Pod::String("post".to_string()));
+        assert_eq!(result.content, "Other stuff");
+
+        let result = matter.parse("---\nnot key value\n---\nOther stuff");
+        assert_eq!(result.data, None, "should get no data on a failed parse");
+        assert_eq!(
+            matter.try_parse("---\nnot key value\n---\nOther stuff"),
+            Err(GrayMatterError::EngineParse(crate::EngineError::new(
+                "expected key=value"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_strip_in_place() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let mut buf = "---\ntitle: Home\n---\n\nSome excerpt\n---\nOther stuff\n".to_string();
+        let capacity = buf.capacity();
+        assert_eq!(
+            matter.strip_in_place(&mut buf),
+            Some("title: Home".to_string())
+        );
+        assert_eq!(buf, "Some excerpt\n---\nOther stuff");
+        assert_eq!(buf.capacity(), capacity, "should reuse the allocation");
+        assert_eq!(
+            matter.strip_in_place(&mut buf),
+            None,
+            "should get no front matter from the body"
+        );
+        assert_eq!(buf, "Some excerpt\n---\nOther stuff");
+
+        matter.trim = false;
+        let mut buf = "---\r\ntitle: Home\r\n---\r\n\r\n  Other stuff\r\n".to_string();
+        assert_eq!(
+            matter.strip_in_place(&mut buf),
+            Some("title: Home".to_string())
+        );
+        assert_eq!(
+            buf, "\r\n  Other stuff\r\n",
+            "should only strip the front matter"
+        );
+    }
+
+    #[test]
+    fn test_bom_and_leading_whitespace() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let input = "\u{feff}  \n  ---\ntitle: Home\n---\nOther stuff";
+        assert!(
+            matter.parse(input).data.is_none(),
+            "should get no front matter after a BOM and whitespace by default"
+        );
+        matter.strip_bom = true;
+        assert!(matter.parse(input).data.is_none());
+        matter.allow_leading_whitespace = true;
+        let result = matter.parse(input);
         assert_eq!(
-            true,
-            result.data == front_matter,
-            "should get front matter as {:?}",
-            front_matter
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string()),
+            "should get front matter after a BOM and whitespace"
         );
-        matter.delimiter = "~~~".to_string();
-        let result = matter.parse("---\nabc: xyz\n---");
-        assert!(result.data.is_none(), "should get no front matter");
-        let result: ParsedEntityStruct<FrontMatter> =
-            matter.parse_with_struct("~~~\nabc: xyz\n~~~").unwrap();
+        assert_eq!(result.content, "Other stuff");
+        assert_eq!(result.orig, input, "should keep the original input");
+        let result = matter.parse("\u{feff}Just content");
         assert_eq!(
-            result.data, front_matter,
-            "{}",
-            "should get front matter by custom delimiter"
+            result.content, "Just content",
+            "should strip the BOM from content"
         );
-        let result = matter.parse("\nabc: xyz\n~~~");
-        assert!(result.data.is_none(), "should get no front matter");
     }
 
     #[test]
-    pub fn test_empty_matter() {
-        let matter: Matter<YAML> = Matter::new();
-        let table = vec![
-            "---\n---\nThis is content",
-            "---\n\n---\nThis is content",
-            "---\n\n\n\n\n\n---\nThis is content",
-            "---\n # this is a comment\n# another one\n# yet another\n---\nThis is content",
-        ];
-        for input in table.into_iter() {
-            let result = matter.parse(input);
-            assert!(result.data.is_none(), "should get no front matter");
-            assert_eq!(
-                result.content, "This is content",
-                "should get content as \"This is content\""
-            );
-        }
+    fn test_disable_body_delimiters() {
+        let mut matter: Matter<YAML> = Matter::new();
+        matter.disable_body_delimiters = true;
+        let input = "---\ntitle: Home\n---\nIntro\n---\nPart one\n---\n\n---\nPart two\n---";
+        let result = matter.parse(input);
+        assert_eq!(
+            result.data.unwrap()["title"],
+            Pod::String("Home".to_string())
+        );
+        assert_eq!(result.excerpt, None);
+        assert_eq!(
+            result.content, "Intro\n---\nPart one\n---\n\n---\nPart two\n---",
+            "should keep every horizontal rule as content"
+        );
+        let result = matter.parse("Intro\n---\nPart one");
+        assert_eq!(result.excerpt, None);
+        assert_eq!(result.content, "Intro\n---\nPart one");
     }
 
     #[test]
-    pub fn test_matter_excerpt() {
-        #[derive(serde::Deserialize, PartialEq)]
-        struct FrontMatter {
-            abc: String,
-        }
+    fn test_content_trim_chars() {
         let mut matter: Matter<YAML> = Matter::new();
-        let result: ParsedEntityStruct<FrontMatter> = matter
-            .parse_with_struct("---\nabc: xyz\n---\nfoo\nbar\nbaz\n---\ncontent")
-            .unwrap();
+        matter.content_trim_chars = Some(vec!['"', '\n']);
+        let input = "---\ntitle: Home\n---\n\"\"\"\n  Other stuff\n\"\"\"\n";
+        let result = matter.parse(input);
+        assert_eq!(result.content, "  Other stuff");
+
+        let mut buf = input.to_string();
+        matter.strip_in_place(&mut buf);
+        assert_eq!(buf, "  Other stuff");
+
+        matter.trim = false;
+        let result = matter.parse(input);
         assert_eq!(
-            result.data.abc,
-            "xyz".to_string(),
-            "should get front matter xyz as value of abc"
+            result.content, "\"\"\"\n  Other stuff\n\"\"\"\n",
+            "should not trim without trim set"
         );
+
+        let matter: Matter<YAML> = Matter::new().with_content_trim_chars(vec!['"']);
+        let result = matter.parse("---\ntitle: Home\n---\n\"Other stuff\"");
         assert_eq!(
-            result.content,
-            "foo\nbar\nbaz\n---\ncontent".to_string(),
-            "should get content as \"foo\nbar\nbaz\n---\ncontent\"",
+            result.content, "Other stuff",
+            "should trim right after the front matter"
         );
+    }
+
+    #[test]
+    fn test_content_transform() {
+        let matter: Matter<YAML> =
+            Matter::new().with_content_transform(|content| content.to_uppercase());
+        let input = "---\ntitle: Home\n---\nSome excerpt\n---\nOther stuff\n";
+        let result = matter.parse(input);
+        assert_eq!(result.content, "SOME EXCERPT\n---\nOTHER STUFF");
+        assert_eq!(result.excerpt, Some("Some excerpt".to_string()));
         assert_eq!(
-            result.excerpt.unwrap(),
-            "foo\nbar\nbaz",
-            "should get an excerpt after front matter"
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string())
         );
-        matter.excerpt_delimiter = Some("<!-- endexcerpt -->".to_string());
-        let result: ParsedEntityStruct<FrontMatter> = matter
-            .parse_with_struct("---\nabc: xyz\n---\nfoo\nbar\nbaz\n<!-- endexcerpt -->\ncontent")
-            .unwrap();
+
+        let mut buf = input.to_string();
+        matter.strip_in_place(&mut buf);
+        assert_eq!(buf, "SOME EXCERPT\n---\nOTHER STUFF");
+    }
+
+    #[test]
+    fn test_with_methods() {
+        let matter = Matter::<YAML>::new()
+            .with_delimiter("~~~")
+            .with_close_delimiter("~~~~")
+            .with_alternate_delimiter("+++", "toml")
+            .with_excerpt_delimiter("<!-- more -->")
+            .with_excerpt_delimiters(vec!["<!-- end -->".to_string()])
+            .with_excerpt_length(80)
+            .with_trim(false)
+            .with_content_trim_chars(vec!['\n'])
+            .with_strip_comments(false)
+            .with_comment_prefix("//")
+            .with_code_fence(true)
+            .with_strip_bom(true)
+            .with_allow_leading_whitespace(true)
+            .with_disable_body_delimiters(true)
+            .with_key_rename(KeyRename::SnakeCase)
+            .with_coerce_bool_strings(true)
+            .with_preserve_line_endings(true)
+            .with_treat_unterminated_as_matter(true)
+            .with_require_balanced_delimiters(true)
+            .with_max_line_bytes(1024)
+            .with_dedent_matter(true)
+            .with_empty_matter_is_some(true);
+        assert_eq!(matter.delimiter, "~~~");
+        assert_eq!(matter.close_delimiter, Some("~~~~".to_string()));
         assert_eq!(
-            true,
-            result.data.abc == "xyz".to_string(),
-            "should get front matter xyz as value of abc"
+            matter.alternate_delimiters,
+            vec![("+++".to_string(), "toml".to_string())]
         );
+        assert_eq!(matter.excerpt_delimiter, Some("<!-- more -->".to_string()));
+        assert_eq!(matter.excerpt_delimiters, vec!["<!-- end -->".to_string()]);
+        assert_eq!(matter.excerpt_length, Some(80));
+        assert!(!matter.trim);
+        assert_eq!(matter.content_trim_chars, Some(vec!['\n']));
+        assert!(!matter.strip_comments);
+        assert_eq!(matter.comment_prefix, Some("//".to_string()));
+        assert!(matter.code_fence);
+        assert!(matter.strip_bom);
+        assert!(matter.allow_leading_whitespace);
+        assert!(matter.disable_body_delimiters);
+        assert_eq!(matter.key_rename, Some(KeyRename::SnakeCase));
+        assert!(matter.coerce_bool_strings);
+        assert!(matter.preserve_line_endings);
+        assert!(matter.treat_unterminated_as_matter);
+        assert!(matter.require_balanced_delimiters);
+        assert_eq!(matter.max_line_bytes, Some(1024));
+        assert!(matter.dedent_matter);
+        assert!(matter.empty_matter_is_some);
+
+        let matter = Matter::<YAML>::default().with_delimiter("+++".to_string());
+        let result = matter.parse("+++\ntitle: Home\n+++\nOther stuff");
         assert_eq!(
-            true,
-            result.content == "foo\nbar\nbaz\n<!-- endexcerpt -->\ncontent".to_string(),
-            "should use a custom separator"
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string())
         );
+    }
+
+    #[test]
+    fn test_preserve_line_endings() {
+        use crate::{LineEnding, StringifyOptions};
+
+        let mut matter: Matter<YAML> = Matter::new();
+        let input =
+            "---\r\ntags:\r\n  - a\r\ntitle: Home\r\n---\r\nSome excerpt\r\n---\r\nOther\r\nstuff";
+        let result = matter.parse(input);
+        assert_eq!(result.content, "Some excerpt\n---\nOther\nstuff");
+
+        matter.preserve_line_endings = true;
+        let result = matter.parse(input);
+        assert_eq!(result.content, "Some excerpt\r\n---\r\nOther\r\nstuff");
+        assert_eq!(result.matter, "tags:\r\n  - a\r\ntitle: Home");
+        assert_eq!(result.excerpt, Some("Some excerpt".to_string()));
+        let data = result.data.unwrap();
+        assert_eq!(data["title"].as_string(), Ok("Home".to_string()));
+
+        let options = StringifyOptions {
+            line_ending: LineEnding::detect(input),
+            ..StringifyOptions::default()
+        };
+        let output = matter.stringify(&data, &result.content, &options).unwrap();
+        assert_eq!(output, input, "should round trip a CRLF document");
+
+        let result = matter.parse("---\ntitle: Home\n---\nOther\r\nstuff\nand more");
         assert_eq!(
-            result.excerpt.unwrap(),
-            "foo\nbar\nbaz",
-            "should get excerpt as \"foo\nbar\nbaz\""
+            result.content, "Other\nstuff\nand more",
+            "should keep LF if it is dominant"
         );
-        let result = matter.parse("foo\nbar\nbaz\n<!-- endexcerpt -->\ncontent");
-        assert!(result.data.is_none(), "should get no front matter");
+    }
+
+    #[test]
+    fn test_postprocessor() {
+        let matter: Matter<YAML> = Matter::new().with_postprocessor(|data, content| {
+            data["word_count"] = Pod::Integer(content.split_whitespace().count() as i64);
+        });
+        let result = matter.parse("---\ntitle: Home\n---\nSome other stuff");
+        let data = result.data.unwrap();
+        assert_eq!(data["word_count"], Pod::Integer(3));
+        assert_eq!(data["title"].as_string(), Ok("Home".to_string()));
         assert_eq!(
-            true,
-            result.content == "foo\nbar\nbaz\n<!-- endexcerpt -->\ncontent".to_string(),
-            "should get content as \"foo\nbar\nbaz\n<!-- endexcerpt -->\ncontent\"",
+            matter.parse("Some other stuff").data,
+            None,
+            "should not be called without front matter"
         );
+    }
+
+    #[test]
+    fn test_escaped_comment() {
+        let matter: Matter<YAML> = Matter::new();
+        let result = matter.parse("---\ntext: |\n  \\# keep\n  more\n---\nOther stuff");
         assert_eq!(
-            result.excerpt.unwrap(),
-            "foo\nbar\nbaz",
-            "should use a custom separator when no front-matter exists"
+            result.data.unwrap()["text"].as_string(),
+            Ok("# keep\nmore".to_string()),
+            "should keep and unescape lines starting with `\\#`"
         );
     }
 
     #[test]
-    fn test_parser() {
-        let matter: Matter<YAML> = Matter::new();
-        let raw = "---whatever\nabc: xyz\n---".to_string();
-        let result = matter.parse(&raw);
+    fn test_comment_prefix() {
+        let mut matter: Matter<JSON> = Matter::new().with_comment_prefix("//");
+        let input = "---\n// comment\n{\n  \"title\": \"Home\",\n  // another one\n\\// kept\n  \"tag\": \"#a\"\n}\n---\nOther stuff";
+        let result = matter.parse(input);
+        assert_eq!(result.stripped_comment_count, 2);
+        assert_eq!(
+            result.matter,
+            "{\n  \"title\": \"Home\",\n\n// kept\n  \"tag\": \"#a\"\n}"
+        );
+
+        let input = "---\n// comment\n{\"title\": \"Home\",\n# not a comment\n\"tag\": \"a\"}\n---";
+        let result = matter.parse(input);
+        assert_eq!(result.stripped_comment_count, 1);
+        assert_eq!(
+            result.matter,
+            "{\"title\": \"Home\",\n# not a comment\n\"tag\": \"a\"}"
+        );
+
+        matter.comment_prefix = None;
+        let result = matter.parse("---\n// comment\n{}\n---");
+        assert_eq!(result.stripped_comment_count, 0);
+        assert_eq!(result.matter, "// comment\n{}");
+        matter.comment_prefix = Some(String::new());
+        assert_eq!(matter.parse("---\n{}\n---").matter, "{}");
+
+        let matter: Matter<TOML> = Matter::new().with_comment_prefix(";");
+        let result = matter.parse("---\n; comment\ntitle = \"Home\"\n  \\; kept = 1\n---");
+        assert_eq!(result.stripped_comment_count, 1);
+        assert_eq!(result.matter, "title = \"Home\"\n  ; kept = 1");
+    }
+
+    #[test]
+    fn test_comments_in_block_scalar() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let input = "---\n# comment\ndescription: |\n  first\n  # not a comment\n\n    # nested\n# comment\nsummary: >-\n  # folded\nlist:\n  - |\n    # item\n  # comment\n---\nOther stuff";
+        let result = matter.parse(input);
+        assert_eq!(result.stripped_comment_count, 3);
+        let data = result.data.unwrap();
+        assert_eq!(
+            data["description"].as_string(),
+            Ok("first\n# not a comment\n\n  # nested\n".to_string()),
+            "should keep `#` lines in a literal block scalar"
+        );
+        assert_eq!(data["summary"].as_string(), Ok("# folded".to_string()));
+        assert_eq!(data["list"][0].as_string(), Ok("# item".to_string()));
+
+        matter.strip_comments = false;
+        let result = matter.parse(input);
+        assert_eq!(result.stripped_comment_count, 0);
+        assert_eq!(
+            result.data.unwrap()["description"].as_string(),
+            Ok("first\n# not a comment\n\n  # nested\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_code_fence() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let input = "```yaml\ntitle: Home\n```\nOther stuff";
+        let result = matter.parse(input);
         assert!(
             result.data.is_none(),
-            "extra characters should get no front matter"
+            "should get no front matter from a code fence by default"
         );
-        assert!(
-            !result.content.is_empty(),
-            "Looks similar to front matter:\n{}\nIs really just content.",
-            raw
+        matter.code_fence = true;
+        let result = matter.parse(input);
+        assert_eq!(
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string()),
+            "should get front matter from a code fence"
         );
-        let result = matter.parse("--- true\n---");
+        assert_eq!(result.matter, "title: Home");
+        assert_eq!(result.content, "Other stuff");
+        let result = matter.parse("````toml\ntitle = \"Home\"\n````\nOther stuff");
+        assert_eq!(
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string()),
+            "should parse front matter with the engine of the info string"
+        );
+        let result = matter.parse("```rust\nfn main() {}\n```\nOther stuff");
         assert!(
             result.data.is_none(),
-            "boolean yaml types should get no front matter"
+            "should get no front matter from a code fence with an unknown language"
         );
-        let result = matter.parse("--- 233\n---");
+        assert_eq!(result.content, "```rust\nfn main() {}\n```\nOther stuff");
+    }
+
+    #[test]
+    fn test_language_tag() {
+        let mut matter: Matter<YAML> = Matter::new();
+        let result = matter.parse("---toml\ntitle = \"Home\"\n---\nOther stuff");
+        assert_eq!(
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string()),
+            "should parse front matter with the engine of the language tag"
+        );
+        assert_eq!(result.matter, "title = \"Home\"");
+        assert_eq!(result.content, "Other stuff");
+        let result = matter.parse("--- JSON \n{\"title\": \"Home\"}\n---\nOther stuff");
+        assert_eq!(
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string())
+        );
+        let result = matter.parse("---rust\nfn main() {}\n---\nOther stuff");
         assert!(
             result.data.is_none(),
-            "number yaml types should get no front matter"
+            "should get no front matter with an unknown language"
         );
-        assert!(
-            matter.parse("").data.is_none(),
-            "Empty string should give `data` = None."
+        matter.delimiter = "+++".to_string();
+        let result = matter.parse("+++toml\ntitle = \"Home\"\n+++\nOther stuff");
+        assert_eq!(result.content, "Other stuff");
+        assert_eq!(
+            result.data.unwrap()["title"].as_string(),
+            Ok("Home".to_string())
         );
-        #[derive(serde::Deserialize, PartialEq, Debug)]
-        struct FrontMatter {
-            abc: String,
-            version: i64,
+    }
+
+    #[test]
+    fn test_with_language() {
+        fn parse_ini(text: &str) -> Result<Pod, String> {
+            let mut data = Pod::new_hash();
+            for line in text.lines() {
+                let (key, value) = line
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected key=value: {}", line))?;
+                data[key.trim()] = Pod::String(value.trim().to_string());
+            }
+            Ok(data)
         }
-        let result: ParsedEntityStruct<FrontMatter> = matter.parse_with_struct("---\nabc: xyz\nversion: 2\n---\n\n<span class=\"alert alert-info\">This is an alert</span>\n").unwrap();
-        let data_expected = FrontMatter {
-            abc: "xyz".to_string(),
-            version: 2,
-        };
+        let matter: Matter<YAML> = Matter::new()
+            .with_language("INI", parse_ini)
+            .with_language("toml", |_: &str| Ok::<_, String>(Pod::new_array()))
+            .with_code_fence(true);
+
+        let result = matter.parse("---ini\ntitle = Home\n---\nOther stuff");
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+        assert_eq!(result.content, "Other stuff");
+        let result = matter.parse("```Ini\ntitle = Home\n```\nOther stuff");
         assert_eq!(
-            true,
-            data_expected == result.data,
-            "should get front matter as {:?}",
-            data_expected
+            result.data.unwrap()["title"].as_str(),
+            Some("Home"),
+            "should match languages case-insensitively, in code fences too"
         );
-        let content_expected =
-            "<span class=\"alert alert-info\">This is an alert</span>".to_string();
+        let result = matter.parse("---toml\ntitle = \"Home\"\n---");
         assert_eq!(
-            result.content, content_expected,
-            "should get content as {:?}",
-            content_expected
+            result.data,
+            Some(Pod::new_array()),
+            "should take precedence over a built-in engine"
         );
-        #[derive(serde::Deserialize, PartialEq, Debug)]
-        struct FrontMatterName {
-            name: String,
+        let result = matter.parse("---json\n{\"title\": \"Home\"}\n---");
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+        let result = matter.parse("---\ntitle: Home\n---");
+        assert_eq!(result.data.unwrap()["title"].as_str(), Some("Home"));
+
+        match matter.try_parse("---ini\ntitle\n---") {
+            Err(GrayMatterError::EngineParse(err)) => {
+                assert_eq!(err.to_string(), "expected key=value: title")
+            }
+            _ => panic!("should fail on malformed front matter"),
         }
-        let result: ParsedEntityStruct<FrontMatterName> = matter
-            .parse_with_struct(
-                r#"---
-name: "troublesome --- value"
----
-here is some content
-"#,
-            )
+    }
+
+    #[test]
+    fn test_parse_timed() {
+        let matter: Matter<YAML> = Matter::new();
+        let mut input = "---\n".to_string();
+        for i in 0..200 {
+            input.push_str(&format!("key{}: value {}\n", i, i));
+        }
+        input.push_str("---\n");
+        for _ in 0..200 {
+            input.push_str("Some content that has to be scanned and assembled.\n");
+        }
+        let (result, timings) = matter.parse_timed(&input);
+        assert_eq!(result, matter.parse(&input), "should parse like `parse`");
+        assert!(timings.scan > Duration::from_nanos(0));
+        assert!(timings.engine > Duration::from_nanos(0));
+        assert!(timings.assembly > Duration::from_nanos(0));
+        assert_eq!(
+            timings.total(),
+            timings.scan + timings.engine + timings.assembly
+        );
+        assert!(timings.total() >= timings.engine);
+    }
+
+    #[test]
+    fn test_stringify() {
+        use crate::engine::JSON;
+        use crate::{LineEnding, Pod, StringifyOptions};
+        let mut matter: Matter<YAML> = Matter::new();
+        let options = StringifyOptions::default();
+        let input = "---\ntags:\n  - a\n  - b\ntitle: Home\n---\nOther stuff";
+        let result = matter.parse(input);
+        let output = matter
+            .stringify(result.data.as_ref().unwrap(), &result.content, &options)
             .unwrap();
-        let data_expected = FrontMatterName {
-            name: "troublesome --- value".to_string(),
+        assert_eq!(output, input, "should round-trip front matter and content");
+        assert_eq!(
+            matter.stringify(&Pod::new_hash(), "Other stuff", &options),
+            Ok("Other stuff".to_string()),
+            "should write no front matter for empty data"
+        );
+        matter.delimiter = "~~~".to_string();
+        let output = matter
+            .stringify(result.data.as_ref().unwrap(), "", &options)
+            .unwrap();
+        assert!(
+            output.starts_with("~~~\n") && output.ends_with("\n~~~\n"),
+            "should use the custom delimiter"
+        );
+        let matter: Matter<JSON> = Matter::new();
+        let output = matter
+            .stringify(result.data.as_ref().unwrap(), "Other stuff", &options)
+            .unwrap();
+        assert_eq!(matter.parse(&output).data, result.data);
+        let options = StringifyOptions {
+            line_ending: LineEnding::Crlf,
+            ..StringifyOptions::default()
         };
+        let output = matter
+            .stringify(result.data.as_ref().unwrap(), "Other\nstuff\r\n", &options)
+            .unwrap();
         assert_eq!(
-            true,
-            result.data == data_expected,
-            "should correctly identify delimiters and ignore strings that look like delimiters and get front matter as {:?}", data_expected
+            output,
+            "---\r\n{\r\n  \"tags\": [\r\n    \"a\",\r\n    \"b\"\r\n  ],\r\n  \"title\": \"Home\"\r\n}\r\n---\r\nOther\r\nstuff\r\n",
+            "should use CRLF line endings throughout"
         );
-        let result: ParsedEntityStruct<FrontMatterName> = matter
-            .parse_with_struct("---\nname: \"troublesome --- value\"\n---")
+
+        #[derive(serde::Serialize)]
+        struct FrontMatter {
+            title: String,
+            tags: Vec<String>,
+        }
+        #[derive(serde::Serialize)]
+        struct Empty {}
+        let matter: Matter<YAML> = Matter::new();
+        let data = FrontMatter {
+            title: "Home".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+        };
+        let options = StringifyOptions::default();
+        let output = matter
+            .stringify_with_struct(&data, "Other stuff\n", &options)
             .unwrap();
         assert_eq!(
-            true,
-            result.data == data_expected,
-            "should correctly parse a string that only has an opening delimiter and get front matter as {:?}", data_expected
+            output, "---\ntags:\n  - a\n  - b\ntitle: Home\n---\nOther stuff\n",
+            "should keep a single trailing newline of the content"
         );
-        let result = matter.parse("-----------name--------------value\nfoo");
-        assert!(
-            result.data.is_none(),
-            "should not try to parse a string has content that looks like front-matter"
+        assert_eq!(
+            matter.stringify_with_struct(&Empty {}, "Other stuff\n", &options),
+            Ok("Other stuff\n".to_string()),
+            "should write no front matter for empty data"
         );
-        let result = matter.parse("---\nname: ---\n---\n---\n");
         assert_eq!(
-            result.content, "---",
-            "should correctly handle rogue delimiter"
+            matter.stringify_with_struct(&None::<FrontMatter>, "Other stuff", &options),
+            Ok("Other stuff".to_string())
         );
-        let result = matter.parse("---\nname: bar\n---\n---\n---");
+    }
+
+    #[test]
+    fn test_reserialize() {
+        let matter: Matter<YAML> = Matter::new();
+        let input =
+            "---\ntitle: Home # the title\ntags: [a, b]\n---\nAn excerpt\n---\nOther stuff\n";
+        let parsed_entity = matter.parse(input);
+        let document = matter.reserialize(&parsed_entity).unwrap();
+        let reparsed = matter.parse(&document);
+        assert_eq!(reparsed.data, parsed_entity.data);
+        assert_eq!(reparsed.content, parsed_entity.content);
+        assert_eq!(reparsed.excerpt, Some("An excerpt".to_string()));
+
+        let input = "---\r\ntitle: Home\r\n---\r\nOther stuff";
+        let document = matter.reserialize(&matter.parse(input)).unwrap();
         assert_eq!(
-            result.content, "---\n---",
-            "should correctly handle two rogue delimiter"
+            document, input,
+            "should keep the line ending of the original input"
+        );
+
+        let parsed_entity = matter.parse("No front matter");
+        assert_eq!(
+            matter.reserialize(&parsed_entity),
+            Ok("No front matter".to_string())
+        );
+
+        let matter: Matter<TOML> = Matter::new().with_delimiter("+++");
+        let input = "+++\ntitle = \"Home\"\n+++\nOther stuff";
+        assert_eq!(
+            matter.reserialize(&matter.parse(input)),
+            Ok(input.to_string())
+        );
+    }
+
+    #[test]
+    fn test_verify_roundtrip() {
+        let matter: Matter<YAML> = Matter::new();
+        let input = "---\ntitle: Home\nratio: 1.0\nsmall: 0.30000000000000004\ntags: [a, '1']\nempty:\n---\nOther stuff";
+        assert_eq!(matter.verify_roundtrip(input), Ok(()));
+        assert_eq!(matter.verify_roundtrip("No front matter"), Ok(()));
+
+        let matter: Matter<TOML> = Matter::new();
+        assert_eq!(
+            matter.verify_roundtrip("---\nratio = 0.1\nnot_a_number = nan\n---"),
+            Ok(()),
+            "should consider NaN equal to itself"
+        );
+
+        let matter: Matter<JSON> = Matter::new();
+        assert_eq!(
+            matter.verify_roundtrip(
+                "---\n{\"ratio\": 1.0, \"nested\": {\"weights\": [0.5, 2.0]}}\n---"
+            ),
+            Err(Error::RoundtripMismatch(vec![
+                "nested.weights.1".to_string(),
+                "ratio".to_string()
+            ])),
+            "should report floats written as integers"
         );
     }
 
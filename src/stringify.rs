@@ -0,0 +1,96 @@
+/// Options controlling how front matter is written by [`Matter::stringify`](crate::Matter::stringify).
+///
+/// Not every option applies to every format. Each [`Stringify`](crate::engine::Stringify)
+/// implementation documents which ones it honors.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use gray_matter::{Matter, Pod, StringifyOptions};
+/// # use gray_matter::engine::YAML;
+/// let matter: Matter<YAML> = Matter::new();
+/// let mut data = Pod::new_hash();
+/// data["title"] = Pod::String("Home".to_owned());
+/// data["author"]["name"] = Pod::String("Jane".to_owned());
+///
+/// let options = StringifyOptions {
+///     indent: 4,
+///     quote_strings: true,
+///     ..StringifyOptions::default()
+/// };
+/// let document = matter.stringify(&data, "Some content", &options).unwrap();
+///
+/// assert_eq!(
+///     document,
+///     "---\nauthor:\n    name: \"Jane\"\ntitle: \"Home\"\n---\nSome content"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StringifyOptions {
+    /// Number of spaces per level of nesting. Defaults to `2`.
+    pub indent: usize,
+    /// Whether strings are always quoted, even when the format allows them to be bare. Defaults
+    /// to `false`.
+    pub quote_strings: bool,
+    /// Whether keys are written in sorted order, for stable output. Defaults to `true`.
+    pub sort_keys: bool,
+    /// Whether the front matter ends with an explicit document end marker, for formats that have
+    /// one. Defaults to `false`.
+    pub document_end: bool,
+    /// The line ending used throughout the written document, including the delimiters and the
+    /// content. Defaults to [`LineEnding::Lf`](crate::LineEnding::Lf).
+    pub line_ending: LineEnding,
+}
+
+impl Default for StringifyOptions {
+    fn default() -> Self {
+        Self {
+            indent: 2,
+            quote_strings: false,
+            sort_keys: true,
+            document_end: false,
+            line_ending: LineEnding::Lf,
+        }
+    }
+}
+
+/// Line endings for written documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`, as used on Unix-like systems.
+    Lf,
+    /// `\r\n`, as used on Windows.
+    Crlf,
+}
+
+impl LineEnding {
+    /// The characters of the line ending, like `"\r\n"` for `Crlf`.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+        }
+    }
+
+    /// The dominant line ending of `text`: `Crlf` if most of its lines end with `\r\n`, and `Lf`
+    /// otherwise.
+    pub fn detect(text: &str) -> Self {
+        let crlf = text.matches("\r\n").count();
+        if crlf > text.matches('\n').count() - crlf {
+            LineEnding::Crlf
+        } else {
+            LineEnding::Lf
+        }
+    }
+
+    /// Converts every line ending in `text` to `self`.
+    pub(crate) fn apply(&self, text: &str) -> String {
+        let normalized = text.replace("\r\n", "\n");
+        match *self {
+            LineEnding::Lf => normalized,
+            LineEnding::Crlf => normalized.replace('\n', "\r\n"),
+        }
+    }
+}
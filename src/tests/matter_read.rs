@@ -1,6 +1,7 @@
 use crate::engine::yaml::YAML;
 use crate::entity::{ParsedEntity, ParsedEntityStruct};
 use crate::matter::Matter;
+use crate::GrayMatterError;
 use serde::de::DeserializeOwned;
 use std::fs;
 use std::path::Path;
@@ -121,3 +122,94 @@ fn test_all_matter() {
         "Parsing `all.yaml` should give `excerpt` = None."
     );
 }
+
+#[test]
+fn test_trailing_delimiter() {
+    #[derive(serde::Deserialize, PartialEq, Debug)]
+    struct FrontMatter {
+        a: String,
+    }
+    let result: ParsedEntityStruct<FrontMatter> =
+        matter_yaml_struct("trailing-delimiter.md").unwrap();
+    assert_eq!(
+        result.data,
+        FrontMatter { a: "b".to_string() },
+        "should get front matter before the trailing delimiter"
+    );
+    assert_eq!(
+        result.content, "---",
+        "should keep a lone trailing delimiter as content"
+    );
+    assert!(
+        result.excerpt.is_none(),
+        "should not get an empty excerpt from a lone trailing delimiter"
+    );
+}
+
+#[test]
+fn test_inspect_excerpt() {
+    let content = read_content("excerpt.md");
+    let matter: Matter<YAML> = Matter::new();
+    let shape = matter.inspect(&content);
+    let result = matter.parse(&content);
+    assert!(shape.has_matter, "should find front matter");
+    assert!(shape.has_excerpt, "should find an excerpt");
+    assert_eq!(shape.matter_len, "title: Excerpt".len());
+    assert_eq!(
+        shape.content_len,
+        "This is an excerpt.\n---\nThis is content.".len()
+    );
+    assert_eq!(shape.matter_len, result.matter.len());
+    assert_eq!(shape.content_len, result.content.len());
+    assert_eq!(result.excerpt, Some("This is an excerpt.".to_string()));
+}
+
+#[test]
+fn test_parse_dir() {
+    let matter: Matter<YAML> = Matter::new();
+    let report = matter.parse_dir(get_fixtures("report")).unwrap();
+    assert_eq!(report.len(), 2, "should report every file");
+    let (path, result) = &report[0];
+    assert!(path.ends_with("good.md"));
+    assert_eq!(
+        result.as_ref().unwrap().data.as_ref().unwrap()["title"].as_str(),
+        Some("Home")
+    );
+    let (path, result) = &report[1];
+    assert!(path.ends_with("malformed.md"));
+    assert!(
+        matches!(result, Err(GrayMatterError::EngineParse(_))),
+        "should report the malformed file instead of stopping"
+    );
+    assert!(matter.parse_dir(get_fixtures("missing")).is_err());
+}
+
+#[test]
+fn test_parse_from_path() {
+    #[derive(serde::Deserialize, PartialEq, Debug)]
+    struct FrontMatter {
+        title: String,
+    }
+    let matter: Matter<YAML> = Matter::new();
+    let result = matter.parse_from_path(get_fixtures("basic.txt")).unwrap();
+    assert_eq!(result, matter_yaml("basic.txt"));
+
+    let result = matter
+        .parse_from_path_with_struct::<FrontMatter, _>(get_fixtures("basic.txt"))
+        .unwrap();
+    assert_eq!(result.data.title, "Basic");
+
+    let err = matter
+        .parse_from_path(get_fixtures("missing.md"))
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    assert!(matches!(
+        matter.parse_from_path_with_struct::<FrontMatter, _>(get_fixtures("missing.md")),
+        Err(GrayMatterError::Io(ref err)) if err.kind() == std::io::ErrorKind::NotFound
+    ));
+    assert_eq!(
+        matter.parse_from_path_with_struct::<FrontMatter, _>(get_fixtures("hasnt-matter.md")),
+        Err(GrayMatterError::NoMatter),
+        "should tell parse errors apart from IO errors"
+    );
+}
@@ -1,2 +1,3 @@
+pub(crate) mod de;
 pub mod error;
 pub mod pod;
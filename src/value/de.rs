@@ -0,0 +1,109 @@
+use crate::Pod;
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{self, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+use std::collections::HashMap;
+
+type Error = serde_json::Error;
+
+/// A `Deserializer` for a [`Pod`](crate::Pod) that accepts strings like `"yes"` wherever a `bool`
+/// is expected. Used by [`Matter::coerce_bool_strings`](crate::Matter::coerce_bool_strings).
+pub(crate) struct BoolStringDeserializer<'a>(pub(crate) &'a Pod);
+
+/// The `bool` a string stands for, if any. Case-insensitive.
+fn bool_from_str(s: &str) -> Option<bool> {
+    match s.to_lowercase().as_str() {
+        "true" | "yes" => Some(true),
+        "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+impl<'a> BoolStringDeserializer<'a> {
+    fn map(
+        hash: &'a HashMap<String, Pod>,
+    ) -> MapDeserializer<'static, impl Iterator<Item = (&'a str, BoolStringDeserializer<'a>)>, Error>
+    {
+        MapDeserializer::new(
+            hash.iter()
+                .map(|(key, val)| (key.as_str(), BoolStringDeserializer(val))),
+        )
+    }
+}
+
+impl<'de, 'a> de::Deserializer<'de> for BoolStringDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match *self.0 {
+            Pod::Null => visitor.visit_unit(),
+            Pod::String(ref val) => visitor.visit_str(val),
+            Pod::Integer(val) => visitor.visit_i64(val),
+            Pod::Float(val) => visitor.visit_f64(val),
+            Pod::Boolean(val) => visitor.visit_bool(val),
+            Pod::Array(ref vec) => {
+                let mut seq = SeqDeserializer::new(vec.iter().map(BoolStringDeserializer));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Pod::Hash(ref hash) => {
+                let mut map = Self::map(hash);
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match *self.0 {
+            Pod::String(ref val) => match bool_from_str(val) {
+                Some(val) => visitor.visit_bool(val),
+                None => self.deserialize_any(visitor),
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match *self.0 {
+            Pod::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match *self.0 {
+            Pod::String(ref val) => visitor.visit_enum(val.as_str().into_deserializer()),
+            Pod::Hash(ref hash) => visitor.visit_enum(MapAccessDeserializer::new(Self::map(hash))),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de, 'a> IntoDeserializer<'de, Error> for BoolStringDeserializer<'a> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
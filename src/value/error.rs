@@ -5,6 +5,9 @@ use std::fmt::{Display, Formatter, Result};
 pub enum Error {
     TypeError(String),
     DeserializeError(String),
+    SerializeError(String),
+    MergeConflict(Vec<String>),
+    RoundtripMismatch(Vec<String>),
 }
 
 impl Error {
@@ -15,6 +18,18 @@ impl Error {
     pub fn deserialize_error(msg: String) -> Self {
         Error::DeserializeError(msg)
     }
+
+    pub fn serialize_error(msg: String) -> Self {
+        Error::SerializeError(msg)
+    }
+
+    pub fn merge_conflict(paths: Vec<String>) -> Self {
+        Error::MergeConflict(paths)
+    }
+
+    pub fn roundtrip_mismatch(paths: Vec<String>) -> Self {
+        Error::RoundtripMismatch(paths)
+    }
 }
 
 impl Display for Error {
@@ -24,6 +39,11 @@ impl Display for Error {
         match *self {
             TypeError(ref s) => write!(f, "Type error, expected: {}", s),
             DeserializeError(ref s) => write!(f, "Deserialize error: {}", s),
+            SerializeError(ref s) => write!(f, "Serialize error: {}", s),
+            MergeConflict(ref paths) => write!(f, "Merge conflict at: {}", paths.join(", ")),
+            RoundtripMismatch(ref paths) => {
+                write!(f, "Round trip mismatch at: {}", paths.join(", "))
+            }
         }
     }
 }
@@ -35,6 +55,9 @@ impl error::Error for Error {
         match *self {
             TypeError(_) => "Type error",
             DeserializeError(_) => "Deserialize error",
+            SerializeError(_) => "Serialize error",
+            MergeConflict(_) => "Merge conflict",
+            RoundtripMismatch(_) => "Round trip mismatch",
         }
     }
 }
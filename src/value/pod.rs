@@ -1,5 +1,6 @@
 use crate::value::error::Error;
 use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use std::collections::HashMap;
 use std::mem;
 use std::ops::{Index, IndexMut};
@@ -24,6 +25,87 @@ pub enum Pod {
 
 static NULL: Pod = Pod::Null;
 
+/// How [`Pod::merge_with_policy`](crate::Pod::merge_with_policy) resolves a key that holds
+/// different values in both hashes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// The value being merged in replaces the existing one.
+    OverrideWins,
+    /// The existing value is kept.
+    DefaultWins,
+    /// Nothing is merged, and the conflicting key paths are returned as an error.
+    Error,
+}
+
+/// Naming conventions [`Pod::rename_keys`](crate::Pod::rename_keys) converts hash keys to. Words
+/// in a key are separated by `_`, `-`, or a change from lowercase to uppercase, so `pageTitle`,
+/// `page_title` and `page-title` are all read as the words `page` and `title`.
+///
+/// In a [`MatterConfig`](crate::MatterConfig), the variants are written the way serde's
+/// `rename_all` attribute spells them: `"snake_case"`, `"camelCase"` and `"kebab-case"`.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyRename {
+    /// `page_title`
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    /// `pageTitle`
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    /// `page-title`
+    #[serde(rename = "kebab-case")]
+    KebabCase,
+}
+
+impl KeyRename {
+    /// Converts a single key to this naming convention.
+    pub fn apply(&self, key: &str) -> String {
+        let words = split_words(key);
+        match *self {
+            KeyRename::SnakeCase => words.join("_"),
+            KeyRename::KebabCase => words.join("-"),
+            KeyRename::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        Some(first) if index > 0 => first.to_uppercase().chain(chars).collect(),
+                        _ => word.clone(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Splits a key into lowercase words, at `_`, `-`, and changes from lowercase to uppercase. A run
+/// of uppercase letters is kept as one word, as in `HTMLTitle`.
+fn split_words(key: &str) -> Vec<String> {
+    let chars: Vec<char> = key.chars().collect();
+    let mut words = vec![];
+    let mut word = String::new();
+    for (index, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' {
+            if !word.is_empty() {
+                words.push(mem::take(&mut word));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if !previous.is_uppercase() || next_is_lowercase {
+                words.push(mem::take(&mut word));
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
 impl Pod {
     /// Deserialize a `Pod` into any struct that implements
     /// [`Deserialize`](https://docs.rs/serde/1.0.127/serde/trait.Deserialize.html).
@@ -32,12 +114,35 @@ impl Pod {
     /// [`serde_json::Value`](https://docs.rs/serde_json/1.0.66/serde_json/enum.Value.html) in
     /// order to work around implementing a custom `Deserializer` for `Pod`.
     pub fn deserialize<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
-        use serde_json::{from_value, Value};
-        let value: Value = self.clone().into();
-        let ret: T = from_value(value)?;
+        use serde_json::from_value;
+        let ret: T = from_value(self.to_json_value())?;
         Ok(ret)
     }
 
+    /// Converts `self` into a [`serde_json::Value`], for tools that take one, like template
+    /// engines. Strings, numbers and booleans map to the matching variants, `Pod::Array` to
+    /// `Value::Array` and `Pod::Hash` to `Value::Object`. Floats that JSON cannot represent, like
+    /// `NaN`, become `Value::Null`. Use `into()` instead to convert without cloning.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let data = matter.parse("---\ntitle: Home\ntags: [a, b]\n---").data.unwrap();
+    ///
+    /// assert_eq!(
+    ///     data.to_json_value(),
+    ///     serde_json::json!({"title": "Home", "tags": ["a", "b"]})
+    /// );
+    /// ```
+    pub fn to_json_value(&self) -> serde_json::Value {
+        self.clone().into()
+    }
+
     pub fn new_array() -> Pod {
         Pod::Array(vec![])
     }
@@ -108,6 +213,17 @@ impl Pod {
         self.len() > 0
     }
 
+    /// Whether the `Pod` is a single value: `Pod::Null`, `Pod::String`, `Pod::Integer`,
+    /// `Pod::Float` or `Pod::Boolean`.
+    pub fn is_scalar(&self) -> bool {
+        !self.is_container()
+    }
+
+    /// Whether the `Pod` holds other values: `Pod::Array` or `Pod::Hash`.
+    pub fn is_container(&self) -> bool {
+        matches!(*self, Pod::Array(_) | Pod::Hash(_))
+    }
+
     pub fn as_string(&self) -> Result<String, Error> {
         match *self {
             Pod::String(ref value) => Ok(value.clone()),
@@ -115,6 +231,14 @@ impl Pod {
         }
     }
 
+    /// Returns the value of a `Pod::String` without copying it, or `None` for any other variant.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            Pod::String(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
     pub fn as_i64(&self) -> Result<i64, Error> {
         match *self {
             Pod::Integer(ref value) => Ok(*value),
@@ -149,6 +273,517 @@ impl Pod {
             _ => Err(Error::type_error("Hash")),
         }
     }
+
+    /// Consumes a `Pod::Array`, returning its elements without cloning them, or `None` for any
+    /// other variant.
+    pub fn into_array(self) -> Option<Vec<Pod>> {
+        match self {
+            Pod::Array(vec) => Some(vec),
+            _ => None,
+        }
+    }
+
+    /// Consumes a `Pod::Hash`, returning its entries without cloning them, or `None` for any
+    /// other variant.
+    pub fn into_map(self) -> Option<HashMap<String, Pod>> {
+        match self {
+            Pod::Hash(hash) => Some(hash),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a `Pod::String`, or `default` for any other variant.
+    pub fn as_string_or(&self, default: &str) -> String {
+        self.as_string().unwrap_or_else(|_| default.to_string())
+    }
+
+    /// Returns the value of a `Pod::Integer`, or `default` for any other variant.
+    pub fn as_i64_or(&self, default: i64) -> i64 {
+        self.as_i64().unwrap_or(default)
+    }
+
+    /// Returns the value of a `Pod::Boolean`, or `default` for any other variant.
+    pub fn as_bool_or(&self, default: bool) -> bool {
+        self.as_bool().unwrap_or(default)
+    }
+
+    /// Returns the elements of a `Pod::Array` as floats, widening any `Pod::Integer` to `f64`.
+    /// Returns `None` for any other variant, or if any element is not a number.
+    pub fn as_f64_array(&self) -> Option<Vec<f64>> {
+        match *self {
+            Pod::Array(ref vec) => vec
+                .iter()
+                .map(|value| match *value {
+                    Pod::Float(val) => Some(val),
+                    Pod::Integer(val) => Some(val as f64),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of a `Pod::Array` as integers. Returns `None` for any other variant,
+    /// or if any element is not a `Pod::Integer`.
+    pub fn as_i64_array(&self) -> Option<Vec<i64>> {
+        match *self {
+            Pod::Array(ref vec) => vec.iter().map(|value| value.as_i64().ok()).collect(),
+            _ => None,
+        }
+    }
+
+    /// Deep merges `other` into `self`, with values from `other` taking precedence. Shorthand for
+    /// [`merge_with_policy`](Pod::merge_with_policy) with
+    /// [`MergePolicy::OverrideWins`](crate::MergePolicy::OverrideWins).
+    pub fn merge(&mut self, other: Pod) {
+        merge_into(self, other, MergePolicy::OverrideWins, "", &mut vec![]);
+    }
+
+    /// Deep merges `other` into `self`, typically a layer of front matter into a layer of
+    /// defaults. Hashes are merged key by key, while any other values present in both are
+    /// resolved according to `policy`. Keys holding equal values are never in conflict.
+    ///
+    /// With [`MergePolicy::Error`](crate::MergePolicy::Error), `self` is left untouched if there
+    /// are conflicts, and the dotted paths of the conflicting keys are returned in
+    /// [`Error::MergeConflict`](crate::Error::MergeConflict).
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Error, MergePolicy, Pod};
+    /// let mut defaults = Pod::new_hash();
+    /// defaults["layout"] = Pod::String("post".to_owned());
+    /// let mut front_matter = Pod::new_hash();
+    /// front_matter["layout"] = Pod::String("page".to_owned());
+    ///
+    /// let result = defaults.merge_with_policy(front_matter, MergePolicy::Error);
+    ///
+    /// assert_eq!(result, Err(Error::MergeConflict(vec!["layout".to_owned()])));
+    /// ```
+    pub fn merge_with_policy(&mut self, other: Pod, policy: MergePolicy) -> IResult<()> {
+        let mut merged = self.clone();
+        let mut conflicts = vec![];
+        merge_into(&mut merged, other, policy, "", &mut conflicts);
+        if !conflicts.is_empty() {
+            conflicts.sort();
+            return Err(Error::merge_conflict(conflicts));
+        }
+        *self = merged;
+        Ok(())
+    }
+
+    /// Merges two arrays of hashes, like navigation menus, by matching the value of `key` in
+    /// each element. An element of `other` with the same `key` as one in `self` is deep merged
+    /// into it, as by [`merge`](Pod::merge), while any other element of `other` is appended.
+    /// Does nothing if `self` or `other` is not a `Pod::Array`.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let mut menu = matter.parse("---\n- id: home\n  url: /\n---").data.unwrap();
+    /// let layer = matter.parse("---\n- id: home\n  url: /index\n- id: blog\n  url: /blog\n---").data.unwrap();
+    ///
+    /// menu.merge_arrays_by_key(&layer, "id");
+    ///
+    /// assert_eq!(menu.len(), 2);
+    /// assert_eq!(menu[0]["url"].as_string(), Ok("/index".to_string()));
+    /// assert_eq!(menu[1]["id"].as_string(), Ok("blog".to_string()));
+    /// ```
+    pub fn merge_arrays_by_key(&mut self, other: &Pod, key: &str) {
+        let (base, other) = match (self, other) {
+            (Pod::Array(ref mut base), Pod::Array(ref other)) => (base, other),
+            _ => return,
+        };
+        for item in other.iter() {
+            let existing = match *item {
+                Pod::Hash(ref hash) => hash.get(key).and_then(|id| {
+                    base.iter_mut().find(|existing| match **existing {
+                        Pod::Hash(ref existing) => existing.get(key) == Some(id),
+                        _ => false,
+                    })
+                }),
+                _ => None,
+            };
+            match existing {
+                Some(existing) => existing.merge(item.clone()),
+                None => base.push(item.clone()),
+            }
+        }
+    }
+
+    /// Renders the `Pod` as an indented tree for human inspection, one value per line along with
+    /// its variant, like `String "Home"`. Hash keys and array indices prefix the values they hold,
+    /// and each level of nesting is indented by two more spaces. Hash keys are sorted.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let data = matter.parse("---\ntitle: Home\ntags: [rust]\n---").data.unwrap();
+    ///
+    /// assert_eq!(
+    ///     data.to_pretty_string(),
+    ///     "Hash\n  tags: Array\n    0: String \"rust\"\n  title: String \"Home\""
+    /// );
+    /// ```
+    pub fn to_pretty_string(&self) -> String {
+        fn render(pod: &Pod, depth: usize, lines: &mut Vec<String>, prefix: String) {
+            let indent = "  ".repeat(depth);
+            let value = match *pod {
+                Pod::Null => "Null".to_string(),
+                Pod::String(ref val) => format!("String {:?}", val),
+                Pod::Integer(val) => format!("Integer {}", val),
+                Pod::Float(val) => format!("Float {:?}", val),
+                Pod::Boolean(val) => format!("Boolean {}", val),
+                Pod::Array(_) => "Array".to_string(),
+                Pod::Hash(_) => "Hash".to_string(),
+            };
+            lines.push(format!("{}{}{}", indent, prefix, value));
+            match *pod {
+                Pod::Array(ref vec) => {
+                    for (index, value) in vec.iter().enumerate() {
+                        render(value, depth + 1, lines, format!("{}: ", index));
+                    }
+                }
+                Pod::Hash(_) => {
+                    for (key, value) in pod.entries_sorted().unwrap_or_default() {
+                        render(value, depth + 1, lines, format!("{}: ", key));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut lines = vec![];
+        render(self, 0, &mut lines, String::new());
+        lines.join("\n")
+    }
+
+    /// Encodes a `Pod::Hash` as a URL query string, like `title=Hello%20World&draft=false`.
+    /// Nested values are flattened into dotted keys, like `author.name` or `tags.0`, and keys are
+    /// sorted for a stable output. `Pod::Null` is encoded as an empty value. Returns an empty
+    /// string for any other variant than `Pod::Hash`.
+    ///
+    /// Requires the `urlencoding` feature.
+    #[cfg(feature = "urlencoding")]
+    pub fn to_query_string(&self) -> String {
+        fn flatten(pod: &Pod, key: String, pairs: &mut Vec<(String, String)>) {
+            match *pod {
+                Pod::Hash(ref hash) => {
+                    for (child_key, value) in hash.iter() {
+                        flatten(value, format!("{}.{}", key, child_key), pairs);
+                    }
+                }
+                Pod::Array(ref vec) => {
+                    for (index, value) in vec.iter().enumerate() {
+                        flatten(value, format!("{}.{}", key, index), pairs);
+                    }
+                }
+                Pod::Null => pairs.push((key, String::new())),
+                Pod::String(ref val) => pairs.push((key, val.clone())),
+                Pod::Integer(val) => pairs.push((key, val.to_string())),
+                Pod::Float(val) => pairs.push((key, val.to_string())),
+                Pod::Boolean(val) => pairs.push((key, val.to_string())),
+            }
+        }
+
+        let mut pairs = vec![];
+        if let Pod::Hash(ref hash) = *self {
+            for (key, value) in hash.iter() {
+                flatten(value, key.clone(), &mut pairs);
+            }
+        }
+        pairs.sort();
+        pairs
+            .iter()
+            .map(|(key, value)| {
+                format!(
+                    "{}={}",
+                    urlencoding::encode(key),
+                    urlencoding::encode(value)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Selects the elements of the `Pod::Array` at the dotted `path` (like `authors` or
+    /// `meta.authors`) for which `predicate` returns `true`. Returns an empty `Vec` if nothing is
+    /// found at `path`, or if it is not an array.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let input = "---\nauthors:\n  - name: Ann\n    role: editor\n  - name: Bob\n    role: writer\n---";
+    /// let data = matter.parse(input).data.unwrap();
+    ///
+    /// let editors = data.select("authors", |author| author["role"].as_string() == Ok("editor".to_string()));
+    ///
+    /// assert_eq!(editors.len(), 1);
+    /// assert_eq!(editors[0]["name"].as_string(), Ok("Ann".to_string()));
+    /// ```
+    pub fn select<F>(&self, path: &str, predicate: F) -> Vec<&Pod>
+    where
+        F: Fn(&Pod) -> bool,
+    {
+        match self.get(path) {
+            Some(Pod::Array(ref vec)) => vec.iter().filter(|item| predicate(item)).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Converts all keys of a `Pod::Hash` to the naming convention `rename`, including the keys
+    /// of nested hashes, also within arrays. Useful for deserializing front matter written in one
+    /// convention, like `pageTitle`, into a struct using another, like `page_title`. If two keys
+    /// end up the same, only one of them is kept.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{KeyRename, Pod};
+    /// let mut data = Pod::new_hash();
+    /// data["pageTitle"] = Pod::String("Home".to_owned());
+    ///
+    /// data.rename_keys(KeyRename::SnakeCase);
+    ///
+    /// assert_eq!(data["page_title"], Pod::String("Home".to_owned()));
+    /// ```
+    pub fn rename_keys(&mut self, rename: KeyRename) {
+        match *self {
+            Pod::Hash(ref mut hash) => {
+                *hash = mem::take(hash)
+                    .into_iter()
+                    .map(|(key, mut value)| {
+                        value.rename_keys(rename);
+                        (rename.apply(&key), value)
+                    })
+                    .collect();
+            }
+            Pod::Array(ref mut vec) => {
+                for value in vec.iter_mut() {
+                    value.rename_keys(rename);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the entries of a `Pod::Hash` sorted by key, for deterministic output. Returns
+    /// `None` for any other variant.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let data = matter.parse("---\ntitle: Home\nauthor: Jane\n---").data.unwrap();
+    ///
+    /// let keys: Vec<&String> = data.entries_sorted().unwrap().into_iter().map(|(key, _)| key).collect();
+    ///
+    /// assert_eq!(keys, vec!["author", "title"]);
+    /// ```
+    pub fn entries_sorted(&self) -> Option<Vec<(&String, &Pod)>> {
+        match *self {
+            Pod::Hash(ref hash) => {
+                let mut entries: Vec<_> = hash.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                Some(entries)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a value exists at the dotted `path` (like `author.name` or `tags.0`). An explicit
+    /// `Pod::Null` at `path` counts as existing, so this tells a key set to null apart from a
+    /// missing one.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let data = matter.parse("---\nauthor:\n  name: Jane\n  email: null\n---").data.unwrap();
+    ///
+    /// assert!(data.path_exists("author.name"));
+    /// assert!(data.path_exists("author.email"));
+    /// assert!(!data.path_exists("author.url"));
+    /// ```
+    pub fn path_exists(&self, path: &str) -> bool {
+        self.get(path).is_some()
+    }
+
+    /// Returns the first value among `keys` that is present and not `Pod::Null`, like
+    /// `subtitle`, then `tagline`. Keys may also be dotted paths, like `meta.description`.
+    /// Returns `None` if none of them holds a value.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, Pod};
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let data = matter.parse("---\ntagline: null\ndescription: A site\n---").data.unwrap();
+    ///
+    /// let subtitle = data.coalesce(&["subtitle", "tagline", "description"]);
+    ///
+    /// assert_eq!(subtitle, Some(&Pod::String("A site".to_owned())));
+    /// ```
+    pub fn coalesce(&self, keys: &[&str]) -> Option<&Pod> {
+        keys.iter()
+            .filter_map(|key| self.get(key))
+            .find(|value| **value != Pod::Null)
+    }
+
+    /// Walks a dotted path through nested `Pod::Hash` keys and `Pod::Array` indices, like
+    /// `author.name` or `tags.0`, and returns the value found there. Returns `None` if any
+    /// segment is missing, or does not match the type of the value it is applied to.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::{Matter, Pod};
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let data = matter.parse("---\nauthor:\n  name: Jane\ntags: [a, b]\n---").data.unwrap();
+    ///
+    /// assert_eq!(data.get("author.name"), Some(&Pod::String("Jane".to_owned())));
+    /// assert_eq!(data.get("tags.1"), Some(&Pod::String("b".to_owned())));
+    /// assert_eq!(data.get("author.name.first"), None);
+    /// ```
+    pub fn get(&self, path: &str) -> Option<&Pod> {
+        path.split('.').try_fold(self, |pod, segment| match *pod {
+            Pod::Hash(ref hash) => hash.get(segment),
+            Pod::Array(ref vec) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| vec.get(index)),
+            _ => None,
+        })
+    }
+
+    /// Returns the number under `key` as a float, whether it was written as an integer like `2`
+    /// or a float like `2.0`. `key` may also be a dotted path, as accepted by [`get`](Pod::get).
+    /// Returns `None` if the key is missing or its value is not a number.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use gray_matter::Matter;
+    /// # use gray_matter::engine::YAML;
+    /// let matter: Matter<YAML> = Matter::new();
+    /// let data = matter.parse("---\nwidth: 2\nheight: 2.5\n---").data.unwrap();
+    ///
+    /// assert_eq!(data.get_number("width"), Some(2.0));
+    /// assert_eq!(data.get_number("height"), Some(2.5));
+    /// ```
+    pub fn get_number(&self, key: &str) -> Option<f64> {
+        match *self.get(key)? {
+            Pod::Integer(value) => Some(value as f64),
+            Pod::Float(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+fn merge_into(
+    base: &mut Pod,
+    other: Pod,
+    policy: MergePolicy,
+    path: &str,
+    conflicts: &mut Vec<String>,
+) {
+    match (base, other) {
+        (Pod::Hash(ref mut base), Pod::Hash(other)) => {
+            for (key, value) in other.into_iter() {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match base.get_mut(&key) {
+                    Some(existing) => merge_into(existing, value, policy, &path, conflicts),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, other) => {
+            if *base == other {
+                return;
+            }
+            match policy {
+                MergePolicy::OverrideWins => *base = other,
+                MergePolicy::DefaultWins => {}
+                MergePolicy::Error => conflicts.push(path.to_string()),
+            }
+        }
+    }
+}
+
+/// Collects the dotted paths at which `a` and `b` differ into `paths`, in sorted order. Two NaN
+/// floats are considered equal.
+pub(crate) fn diff_paths(a: &Pod, b: &Pod, path: &str, paths: &mut Vec<String>) {
+    let child_path = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    match (a, b) {
+        (Pod::Hash(ref a), Pod::Hash(ref b)) => {
+            let mut keys: Vec<&String> = a
+                .keys()
+                .chain(b.keys().filter(|key| !a.contains_key(*key)))
+                .collect();
+            keys.sort();
+            for key in keys {
+                match (a.get(key), b.get(key)) {
+                    (Some(a), Some(b)) => diff_paths(a, b, &child_path(key), paths),
+                    _ => paths.push(child_path(key)),
+                }
+            }
+        }
+        (Pod::Array(ref a), Pod::Array(ref b)) if a.len() == b.len() => {
+            for (index, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                diff_paths(a, b, &child_path(&index.to_string()), paths);
+            }
+        }
+        (Pod::Float(a), Pod::Float(b)) if a.is_nan() && b.is_nan() => {}
+        _ if a == b => {}
+        _ => paths.push(path.to_string()),
+    }
 }
 
 impl Into<String> for Pod {
@@ -226,7 +861,8 @@ impl From<HashMap<String, Pod>> for Pod {
 impl Index<usize> for Pod {
     type Output = Pod;
 
-    /// Easily access element of Pod::Array by usize index
+    /// Easily access element of Pod::Array by usize index. Returns `Pod::Null` if the index is
+    /// out of bounds, or if `self` is not a `Pod::Array`.
     fn index(&self, index: usize) -> &Self::Output {
         match *self {
             Pod::Array(ref vec) => vec.get(index).unwrap_or(&NULL),
@@ -260,7 +896,9 @@ impl IndexMut<usize> for Pod {
 impl<'a> Index<&'a str> for Pod {
     type Output = Pod;
 
-    /// Easily access value of Pod::Hash by &str index
+    /// Easily access value of Pod::Hash by &str index, so lookups can be chained like
+    /// `data["author"]["name"]`. Panics if `self` is a `Pod::Hash` without the key; use
+    /// [`Pod::get`] to look up a key that may be missing.
     fn index(&self, index: &'a str) -> &Self::Output {
         self.index(index.to_string())
     }
@@ -276,7 +914,8 @@ impl<'a> IndexMut<&'a str> for Pod {
 impl Index<String> for Pod {
     type Output = Pod;
 
-    /// Easily access value of Pod::Hash by String index
+    /// Easily access value of Pod::Hash by String index. Panics if `self` is a `Pod::Hash`
+    /// without the key, and returns `Pod::Null` if `self` is not a `Pod::Hash`.
     fn index(&self, index: String) -> &Self::Output {
         match *self {
             Pod::Hash(ref hash) => &hash[&index],
@@ -327,6 +966,30 @@ impl Into<serde_json::Value> for Pod {
     }
 }
 
+impl From<serde_json::Value> for Pod {
+    /// Converts a `serde_json::Value` into a `Pod`, like one built with `json!`. Integral numbers
+    /// become `Pod::Integer` and other numbers `Pod::Float`, the way the engines tell them apart,
+    /// so `2` and `2.0` stay distinct. Integers too large for an `i64` become `Pod::Float`.
+    fn from(val: serde_json::Value) -> Self {
+        use serde_json::Value::*;
+        match val {
+            Null => Pod::Null,
+            String(val) => Pod::String(val),
+            Number(val) => match val.as_i64() {
+                Some(val) => Pod::Integer(val),
+                None => Pod::Float(val.as_f64().unwrap_or(f64::NAN)),
+            },
+            Bool(val) => Pod::Boolean(val),
+            Array(val) => Pod::Array(val.into_iter().map(Pod::from).collect()),
+            Object(val) => Pod::Hash(
+                val.into_iter()
+                    .map(|(key, value)| (key, value.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
 #[test]
 fn test_partial_compare_null() -> std::result::Result<(), Error> {
     assert_eq!(true, Pod::Null == Pod::Null);
@@ -392,6 +1055,23 @@ fn test_partial_compare_hash() -> std::result::Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_partial_compare_hash_key_order() -> std::result::Result<(), Error> {
+    use crate::engine::{Engine, JSON, YAML};
+
+    let first =
+        YAML::parse("title: Home\nauthor:\n  name: Jane\n  email: jane@example.com").unwrap();
+    let second =
+        YAML::parse("author:\n  email: jane@example.com\n  name: Jane\ntitle: Home").unwrap();
+    assert_eq!(first, second, "key order should not matter");
+    let third = JSON::parse(
+        r#"{"author": {"email": "jane@example.com", "name": "Jane"}, "title": "Home"}"#,
+    )
+    .unwrap();
+    assert_eq!(first, third, "key order should not matter across engines");
+    Ok(())
+}
+
 #[test]
 fn test_partial_compare_integer() -> std::result::Result<(), Error> {
     let a = Pod::Integer(16);
@@ -461,6 +1141,26 @@ fn test_index_str() -> std::result::Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_index_chained() -> std::result::Result<(), Error> {
+    use crate::engine::{Engine, YAML};
+
+    let data = YAML::parse("author:\n  name: Jane\ntags: [a, b]").unwrap();
+    assert_eq!(data["author"]["name"], Pod::String("Jane".to_string()));
+    assert_eq!(data["tags"][1], Pod::String("b".to_string()));
+    assert_eq!(data["tags"][2], Pod::Null);
+    assert_eq!(data["author"]["name"][0], Pod::Null);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_index_missing_key() {
+    let mut data = Pod::new_hash();
+    data["title"] = Pod::String("Home".to_string());
+    let _ = &data["author"];
+}
+
 #[test]
 fn test_pod_from_into() -> std::result::Result<(), Error> {
     let a: String = Pod::from("hello".to_string()).into();
@@ -501,3 +1201,525 @@ fn test_pod_deserialize() -> std::result::Result<(), Error> {
     assert_eq!(true, cfg == cfg_expected);
     Ok(())
 }
+
+#[test]
+fn test_pod_deserialize_tuple() -> std::result::Result<(), Error> {
+    use serde::Deserialize;
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Config {
+        point: (i64, i64),
+        range: (String, f64),
+        color: [f64; 3],
+    }
+    let mut pod = Pod::new_hash();
+    pod["point"] = Pod::Array(vec![Pod::Integer(1), Pod::Integer(2)]);
+    pod["range"] = Pod::Array(vec![Pod::String("to".into()), Pod::Float(2.5)]);
+    pod["color"] = Pod::Array(vec![Pod::Float(0.5), Pod::Integer(1), Pod::Float(0.25)]);
+    let cfg: Config = pod.deserialize()?;
+    assert_eq!(
+        cfg,
+        Config {
+            point: (1, 2),
+            range: ("to".to_string(), 2.5),
+            color: [0.5, 1.0, 0.25],
+        }
+    );
+    pod["point"].push(Pod::Integer(3))?;
+    assert!(
+        pod.deserialize::<Config>().is_err(),
+        "should not deserialize an array of the wrong length into a tuple"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_as_str() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_hash();
+    pod["title"] = Pod::String("hello".into());
+    pod["count"] = Pod::Integer(3);
+    assert_eq!(pod["title"].as_str(), Some("hello"));
+    assert_eq!(pod["count"].as_str(), None);
+    assert_eq!(Pod::Null.as_str(), None);
+    Ok(())
+}
+
+#[test]
+fn test_into_array_into_map() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_array();
+    pod.push(Pod::String("a".into()))?;
+    pod.push(Pod::Integer(1))?;
+    let ptr = pod[0].as_str().unwrap().as_ptr();
+    let vec = pod.into_array().unwrap();
+    assert_eq!(vec, vec![Pod::String("a".into()), Pod::Integer(1)]);
+    assert_eq!(
+        vec[0].as_str().unwrap().as_ptr(),
+        ptr,
+        "should move the elements instead of cloning them"
+    );
+
+    let mut pod = Pod::new_hash();
+    pod["title"] = Pod::String("Home".into());
+    let map = pod.clone().into_map().unwrap();
+    assert_eq!(map.len(), 1);
+    assert_eq!(map["title"], Pod::String("Home".into()));
+    assert_eq!(pod.into_array(), None);
+    assert_eq!(Pod::Null.into_map(), None);
+    Ok(())
+}
+
+#[test]
+fn test_as_or() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_hash();
+    pod["title"] = Pod::String("hello".into());
+    pod["count"] = Pod::Integer(3);
+    pod["draft"] = Pod::Boolean(true);
+    pod["nothing"] = Pod::Null;
+    assert_eq!(pod["title"].as_string_or("untitled"), "hello");
+    assert_eq!(pod["count"].as_string_or("untitled"), "untitled");
+    assert_eq!(pod["nothing"].as_string_or("untitled"), "untitled");
+    assert_eq!(pod["count"].as_i64_or(0), 3);
+    assert_eq!(pod["title"].as_i64_or(0), 0);
+    assert_eq!(Pod::Null.as_i64_or(0), 0);
+    assert!(pod["draft"].as_bool_or(false));
+    assert!(pod["title"].as_bool_or(true));
+    assert!(!pod["nothing"].as_bool_or(false));
+    Ok(())
+}
+
+#[test]
+fn test_merge_with_policy() -> std::result::Result<(), Error> {
+    let mut defaults = Pod::new_hash();
+    defaults["layout"] = Pod::String("post".into());
+    defaults["author"]["name"] = Pod::String("Anonymous".into());
+    defaults["author"]["email"] = Pod::String("none".into());
+    defaults["draft"] = Pod::Boolean(false);
+    let mut front_matter = Pod::new_hash();
+    front_matter["layout"] = Pod::String("page".into());
+    front_matter["author"]["name"] = Pod::String("Jane".into());
+    front_matter["draft"] = Pod::Boolean(false);
+    front_matter["title"] = Pod::String("Home".into());
+
+    let mut merged = defaults.clone();
+    merged.merge_with_policy(front_matter.clone(), MergePolicy::OverrideWins)?;
+    assert_eq!(merged["layout"], Pod::String("page".into()));
+    assert_eq!(merged["author"]["name"], Pod::String("Jane".into()));
+    assert_eq!(merged["author"]["email"], Pod::String("none".into()));
+    assert_eq!(merged["title"], Pod::String("Home".into()));
+    let mut merged_default = defaults.clone();
+    merged_default.merge(front_matter.clone());
+    assert_eq!(merged, merged_default);
+
+    let mut merged = defaults.clone();
+    merged.merge_with_policy(front_matter.clone(), MergePolicy::DefaultWins)?;
+    assert_eq!(merged["layout"], Pod::String("post".into()));
+    assert_eq!(merged["author"]["name"], Pod::String("Anonymous".into()));
+    assert_eq!(merged["title"], Pod::String("Home".into()));
+
+    let mut merged = defaults.clone();
+    assert_eq!(
+        merged.merge_with_policy(front_matter, MergePolicy::Error),
+        Err(Error::MergeConflict(vec![
+            "author.name".to_string(),
+            "layout".to_string()
+        ]))
+    );
+    assert_eq!(merged, defaults, "should not merge anything on conflicts");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "urlencoding")]
+fn test_to_query_string() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_hash();
+    pod["title"] = Pod::String("Hello World & more".into());
+    pod["draft"] = Pod::Boolean(false);
+    pod["author"]["name"] = Pod::String("Jane".into());
+    pod["tags"] = Pod::Array(vec![Pod::String("a/b".into()), Pod::Integer(2)]);
+    pod["nothing"] = Pod::Null;
+    assert_eq!(
+        pod.to_query_string(),
+        "author.name=Jane&draft=false&nothing=&tags.0=a%2Fb&tags.1=2&title=Hello%20World%20%26%20more"
+    );
+    assert_eq!(Pod::String("hello".into()).to_query_string(), "");
+    Ok(())
+}
+
+#[test]
+fn test_select() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_hash();
+    pod["meta"]["authors"] = Pod::new_array();
+    for (name, role) in [("Ann", "editor"), ("Bob", "writer"), ("Cid", "editor")].iter() {
+        let mut author = Pod::new_hash();
+        author["name"] = Pod::String(name.to_string());
+        author["role"] = Pod::String(role.to_string());
+        pod["meta"]["authors"].push(author)?;
+    }
+    let is_editor = |author: &Pod| author["role"] == Pod::String("editor".into());
+    let editors = pod.select("meta.authors", is_editor);
+    assert_eq!(editors.len(), 2);
+    assert_eq!(editors[0]["name"], Pod::String("Ann".into()));
+    assert_eq!(editors[1]["name"], Pod::String("Cid".into()));
+    assert!(pod.select("meta.missing", is_editor).is_empty());
+    assert!(pod.select("meta", is_editor).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_numeric_arrays() -> std::result::Result<(), Error> {
+    use crate::engine::YAML;
+    use crate::Matter;
+
+    let data = Matter::<YAML>::new()
+        .parse("---\nweights: [1, 2, 3]\nmixed: [1, 2.5, x]\n---")
+        .data
+        .unwrap();
+    assert_eq!(data["weights"].as_f64_array(), Some(vec![1.0, 2.0, 3.0]));
+    assert_eq!(data["weights"].as_i64_array(), Some(vec![1, 2, 3]));
+    assert_eq!(data["mixed"].as_i64_array(), None);
+    assert_eq!(data["mixed"].as_f64_array(), None);
+    assert_eq!(Pod::Integer(1).as_f64_array(), None);
+    Ok(())
+}
+
+#[test]
+fn test_path_exists() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_hash();
+    pod["author"] = Pod::new_hash();
+    pod["author"]["name"] = Pod::String("Jane".into());
+    pod["author"]["email"] = Pod::Null;
+    pod["tags"] = Pod::new_array();
+    pod["tags"].push(Pod::String("rust".into()))?;
+
+    assert!(pod.path_exists("author.name"));
+    assert!(pod.path_exists("tags.0"));
+    assert!(
+        pod.path_exists("author.email"),
+        "null should count as existing"
+    );
+    assert!(!pod.path_exists("author.url"));
+    assert!(!pod.path_exists("tags.1"));
+    assert!(!pod.path_exists("author.name.first"));
+    Ok(())
+}
+
+#[test]
+fn test_coalesce() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_hash();
+    pod["tagline"] = Pod::Null;
+    pod["description"] = Pod::String("A site".into());
+    pod["meta"] = Pod::new_hash();
+    pod["meta"]["title"] = Pod::String("Home".into());
+
+    assert_eq!(
+        pod.coalesce(&["subtitle", "tagline", "description"]),
+        Some(&Pod::String("A site".into()))
+    );
+    assert_eq!(
+        pod.coalesce(&["meta.title", "description"]),
+        Some(&Pod::String("Home".into()))
+    );
+    assert_eq!(pod.coalesce(&["subtitle", "tagline"]), None);
+    assert_eq!(pod.coalesce(&[]), None);
+    Ok(())
+}
+
+#[test]
+fn test_merge_arrays_by_key() -> std::result::Result<(), Error> {
+    fn entry(id: &str, url: &str) -> Pod {
+        let mut hash = Pod::new_hash();
+        hash["id"] = Pod::String(id.into());
+        hash["url"] = Pod::String(url.into());
+        hash
+    }
+
+    let mut base = Pod::new_array();
+    base.push(entry("home", "/"))?;
+    base.push(entry("about", "/about"))?;
+    base[0]["weight"] = Pod::Integer(1);
+    let mut other = Pod::new_array();
+    other.push(entry("blog", "/blog"))?;
+    other.push(entry("home", "/index"))?;
+    base.merge_arrays_by_key(&other, "id");
+
+    let mut expected = Pod::new_array();
+    expected.push(entry("home", "/index"))?;
+    expected.push(entry("about", "/about"))?;
+    expected.push(entry("blog", "/blog"))?;
+    expected[0]["weight"] = Pod::Integer(1);
+    assert_eq!(base, expected);
+
+    let mut hash = Pod::new_hash();
+    hash.merge_arrays_by_key(&other, "id");
+    assert_eq!(hash, Pod::new_hash(), "should not merge into a non-array");
+    Ok(())
+}
+
+#[test]
+fn test_to_pretty_string() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_hash();
+    pod["title"] = Pod::String("Home".into());
+    pod["author"] = Pod::new_hash();
+    pod["author"]["name"] = Pod::String("Jane".into());
+    pod["author"]["links"] = Pod::new_array();
+    pod["author"]["links"].push(Pod::Null)?;
+    pod["author"]["links"].push(Pod::Float(1.0))?;
+    pod["draft"] = Pod::Boolean(false);
+
+    assert_eq!(
+        pod.to_pretty_string(),
+        [
+            "Hash",
+            "  author: Hash",
+            "    links: Array",
+            "      0: Null",
+            "      1: Float 1.0",
+            "    name: String \"Jane\"",
+            "  draft: Boolean false",
+            "  title: String \"Home\"",
+        ]
+        .join("\n")
+    );
+    assert_eq!(Pod::Integer(1).to_pretty_string(), "Integer 1");
+    Ok(())
+}
+
+#[test]
+fn test_is_scalar_is_container() -> std::result::Result<(), Error> {
+    let scalars = [
+        Pod::Null,
+        Pod::String("hello".into()),
+        Pod::Integer(1),
+        Pod::Float(1.0),
+        Pod::Boolean(true),
+    ];
+    for pod in scalars.iter() {
+        assert!(pod.is_scalar(), "{:?} should be a scalar", pod);
+        assert!(!pod.is_container(), "{:?} should not be a container", pod);
+    }
+    for pod in [Pod::new_array(), Pod::new_hash()].iter() {
+        assert!(pod.is_container(), "{:?} should be a container", pod);
+        assert!(!pod.is_scalar(), "{:?} should not be a scalar", pod);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_entries_sorted() -> std::result::Result<(), Error> {
+    let mut pod = Pod::new_hash();
+    for key in ["title", "author", "date", "tags", "b", "a"].iter() {
+        pod[*key] = Pod::String(key.to_string());
+    }
+    let entries = pod.entries_sorted().unwrap();
+    let keys: Vec<&str> = entries.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(keys, vec!["a", "author", "b", "date", "tags", "title"]);
+    assert!(entries
+        .iter()
+        .all(|(key, value)| value.as_string() == Ok(key.to_string())));
+    assert_eq!(Pod::new_array().entries_sorted(), None);
+    Ok(())
+}
+
+#[test]
+fn test_pod_deserialize_cow() -> std::result::Result<(), Error> {
+    use std::borrow::Cow;
+
+    #[derive(serde::Deserialize, PartialEq, Debug)]
+    struct FrontMatter<'a> {
+        title: Cow<'a, str>,
+        tags: Vec<Cow<'a, str>>,
+    }
+    let mut pod = Pod::new_hash();
+    pod["title"] = Pod::String("Home".into());
+    pod["tags"] = Pod::new_array();
+    pod["tags"].push(Pod::String("rust".into()))?;
+
+    let front_matter: FrontMatter = pod.deserialize()?;
+    assert_eq!(front_matter.title, "Home");
+    assert_eq!(front_matter.tags, vec!["rust"]);
+    assert!(
+        matches!(front_matter.title, Cow::Owned(_)),
+        "should own strings, as a `Pod` has nothing to borrow from"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pod_deserialize_untagged() -> std::result::Result<(), Error> {
+    use crate::engine::{Engine, YAML};
+
+    #[derive(serde::Deserialize, PartialEq, Debug)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    #[derive(serde::Deserialize, PartialEq, Debug)]
+    struct FrontMatter {
+        author: OneOrMany,
+    }
+
+    let front_matter: FrontMatter = YAML::parse("author: x").unwrap().deserialize()?;
+    assert_eq!(front_matter.author, OneOrMany::One("x".into()));
+    let front_matter: FrontMatter = YAML::parse("author: [x, y]").unwrap().deserialize()?;
+    assert_eq!(
+        front_matter.author,
+        OneOrMany::Many(vec!["x".into(), "y".into()])
+    );
+    assert!(YAML::parse("author: 1")
+        .unwrap()
+        .deserialize::<FrontMatter>()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_to_json_value() -> std::result::Result<(), Error> {
+    use serde_json::json;
+
+    let value = json!({
+        "title": "Home",
+        "count": 2,
+        "ratio": 2.0,
+        "draft": false,
+        "tags": ["a", 1, null],
+        "author": {"name": "Jane", "links": []},
+    });
+    let pod: Pod = value.clone().into();
+    assert_eq!(pod["count"], Pod::Integer(2));
+    assert_eq!(pod["ratio"], Pod::Float(2.0));
+    assert_eq!(pod.to_json_value(), value, "should round-trip");
+    let converted: serde_json::Value = pod.into();
+    assert_eq!(converted, value);
+    assert_eq!(Pod::Float(f64::NAN).to_json_value(), json!(null));
+    Ok(())
+}
+
+#[test]
+fn test_pod_from_json_value() -> std::result::Result<(), Error> {
+    use crate::engine::{Engine, YAML};
+    use serde_json::json;
+
+    let pod = Pod::from(json!([2, -2, 2.0, 2.5, u64::MAX]));
+    assert_eq!(
+        pod,
+        Pod::Array(vec![
+            Pod::Integer(2),
+            Pod::Integer(-2),
+            Pod::Float(2.0),
+            Pod::Float(2.5),
+            Pod::Float(u64::MAX as f64),
+        ])
+    );
+    let value: serde_json::Value =
+        serde_json::from_str(r#"{"int": 42, "float": 3.5, "whole": 1.0}"#).unwrap();
+    let pod: Pod = value.into();
+    assert_eq!(
+        pod,
+        YAML::parse("int: 42\nfloat: 3.5\nwhole: 1.0").unwrap(),
+        "should tell integers and floats apart like the engines"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pod_deserialize_json_value() -> std::result::Result<(), Error> {
+    use crate::engine::{Engine, YAML};
+    use crate::value::de::BoolStringDeserializer;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(serde::Deserialize, PartialEq, Debug)]
+    struct FrontMatter {
+        title: String,
+        meta: serde_json::Value,
+        extra: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let pod = YAML::parse(
+        "title: Home\nmeta:\n  tags: [a, 1, 1.5, true, ~]\n  author:\n    name: Jane\nextra:\n  draft: \"yes\"",
+    )
+    .unwrap();
+    let expected = FrontMatter {
+        title: "Home".into(),
+        meta: json!({
+            "tags": ["a", 1, 1.5, true, null],
+            "author": {"name": "Jane"},
+        }),
+        extra: json!({"draft": "yes"}).as_object().unwrap().clone(),
+    };
+    assert_eq!(pod.deserialize::<FrontMatter>()?, expected);
+    assert_eq!(
+        FrontMatter::deserialize(BoolStringDeserializer(&pod))?,
+        expected,
+        "should keep strings in arbitrary values when coercing booleans"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_get() -> std::result::Result<(), Error> {
+    use crate::engine::{Engine, YAML};
+
+    let data = YAML::parse(
+        "title: Home\nauthor:\n  name: Jane\n  links: [{url: x}]\ntags: [a, b]\nempty: ~",
+    )
+    .unwrap();
+    assert_eq!(data.get("title"), Some(&Pod::String("Home".to_string())));
+    assert_eq!(
+        data.get("author.name"),
+        Some(&Pod::String("Jane".to_string()))
+    );
+    assert_eq!(
+        data.get("author.links.0.url"),
+        Some(&Pod::String("x".to_string()))
+    );
+    assert_eq!(data.get("tags.1"), Some(&Pod::String("b".to_string())));
+    assert_eq!(data.get("empty"), Some(&Pod::Null));
+    assert_eq!(data.get("tags.2"), None, "should not panic out of bounds");
+    assert_eq!(data.get("tags.first"), None);
+    assert_eq!(data.get("title.0"), None);
+    assert_eq!(data.get("author.email"), None);
+    assert_eq!(data.get(""), None);
+    assert_eq!(Pod::Null.get("title"), None);
+    Ok(())
+}
+
+#[test]
+fn test_get_number() -> std::result::Result<(), Error> {
+    use crate::engine::{Engine, YAML};
+
+    let int = YAML::parse("count: 2").unwrap();
+    let float = YAML::parse("count: 2.0").unwrap();
+    assert_eq!(int.get_number("count"), Some(2.0));
+    assert_eq!(float.get_number("count"), Some(2.0));
+
+    let data = YAML::parse("size:\n  width: -3\ntitle: Home\nflag: true").unwrap();
+    assert_eq!(data.get_number("size.width"), Some(-3.0));
+    assert_eq!(data.get_number("title"), None);
+    assert_eq!(data.get_number("flag"), None);
+    assert_eq!(data.get_number("missing"), None);
+    Ok(())
+}
+
+#[test]
+fn test_rename_keys() -> std::result::Result<(), Error> {
+    assert_eq!(KeyRename::SnakeCase.apply("pageTitle"), "page_title");
+    assert_eq!(KeyRename::SnakeCase.apply("HTMLTitle"), "html_title");
+    assert_eq!(KeyRename::CamelCase.apply("page-title"), "pageTitle");
+    assert_eq!(KeyRename::KebabCase.apply("page_title"), "page-title");
+
+    let mut pod = Pod::new_hash();
+    pod["pageTitle"] = Pod::String("Home".into());
+    pod["authorList"] = Pod::new_array();
+    pod["authorList"].push(Pod::new_hash())?;
+    pod["authorList"][0]["firstName"] = Pod::String("Jane".into());
+    pod.rename_keys(KeyRename::SnakeCase);
+
+    let mut expected = Pod::new_hash();
+    expected["page_title"] = Pod::String("Home".into());
+    expected["author_list"] = Pod::new_array();
+    expected["author_list"].push(Pod::new_hash())?;
+    expected["author_list"][0]["first_name"] = Pod::String("Jane".into());
+    assert_eq!(pod, expected);
+    Ok(())
+}